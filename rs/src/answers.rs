@@ -0,0 +1,344 @@
+use crate::days::Entry;
+use crate::{Answer, AocError, Part};
+use std::collections::BTreeMap;
+
+// The answers file (in the crate root) and the version of its format.
+pub const FILE_NAME: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml");
+pub const VERSION: usize = 1;
+
+// Answers is the database of known answers keyed by day, part and input name.
+//
+// The file is a small subset of TOML: a "version" key followed by one "[dayNN.partN]" table per
+// puzzle part whose keys are input names and whose values are the answers (integers or strings).
+#[derive(Debug, Clone, Default)]
+pub struct Answers {
+    answers: BTreeMap<(usize, Part, String), String>,
+}
+
+impl Answers {
+    pub fn load(name: &str) -> Result<Self, AocError> {
+        let input = crate::read_file(name)?;
+
+        Self::parse(&input).map_err(|e| e.in_file(name))
+    }
+
+    pub fn parse(s: &str) -> Result<Self, AocError> {
+        let mut rv = Self::default();
+        let mut version = None;
+        let mut section = None;
+
+        for line in parser::parse(s)? {
+            match line {
+                parser::Line::Section(day, part) => {
+                    let part = Part::from_number(part).ok_or_else(|| {
+                        AocError::invalid_input(
+                            format!("invalid part {} for day {}", part, day).as_str(),
+                        )
+                    })?;
+
+                    section = Some((day, part));
+                }
+                parser::Line::Value(key, value) => match section {
+                    Some((day, part)) => {
+                        rv.answers.insert((day, part, key), value);
+                    }
+                    None if key == "version" => version = Some(value),
+                    None => {
+                        return Err(AocError::invalid_input(
+                            format!("key '{}' is not within a [dayNN.partN] table", key).as_str(),
+                        ))
+                    }
+                },
+            }
+        }
+
+        match version {
+            Some(v) if v == VERSION.to_string() => Ok(rv),
+            Some(v) => Err(AocError::invalid_input(
+                format!("unsupported answers version {} (expected {})", v, VERSION).as_str(),
+            )),
+            None => Err(AocError::invalid_input("answers are missing a version")),
+        }
+    }
+
+    pub fn get(&self, day: usize, part: Part, input: &str) -> Option<&str> {
+        self.answers
+            .get(&(day, part, input.to_string()))
+            .map(|v| v.as_str())
+    }
+
+    // The input names that have an answer for a day (in either part).
+    pub fn input_names(&self, day: usize) -> Vec<&str> {
+        let mut rv: Vec<_> = self
+            .answers
+            .keys()
+            .filter(|(d, _, _)| *d == day)
+            .map(|(_, _, input)| input.as_str())
+            .collect();
+
+        rv.sort_unstable();
+        rv.dedup();
+
+        rv
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail { expected: String, actual: String },
+    Error(String),
+    MissingAnswer(String),
+    MissingInput,
+}
+
+impl Outcome {
+    fn check(expected: Option<&str>, actual: Result<Answer, AocError>) -> Self {
+        match (expected, actual) {
+            (_, Err(err)) => Self::Error(err.to_string()),
+            (None, Ok(actual)) => Self::MissingAnswer(actual.to_string()),
+            (Some(expected), Ok(actual)) => {
+                let actual = actual.to_string();
+                if expected == actual {
+                    Self::Pass
+                } else {
+                    Self::Fail {
+                        expected: expected.to_string(),
+                        actual,
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Pass => write!(fmt, "pass"),
+            Self::Fail { expected, actual } => {
+                if !expected.contains('\n') && !actual.contains('\n') {
+                    return write!(fmt, "FAIL: expected {}, got {}", expected, actual);
+                }
+
+                // show multi-line answers as a line by line diff
+                write!(fmt, "FAIL:")?;
+                let expected: Vec<_> = expected.lines().collect();
+                let actual: Vec<_> = actual.lines().collect();
+                (0..expected.len().max(actual.len())).try_for_each(|i| {
+                    match (expected.get(i), actual.get(i)) {
+                        (Some(e), Some(a)) if e == a => write!(fmt, "\n   {}", e),
+                        (e, a) => {
+                            if let Some(e) = e {
+                                write!(fmt, "\n - {}", e)?;
+                            }
+                            if let Some(a) = a {
+                                write!(fmt, "\n + {}", a)?;
+                            }
+
+                            Ok(())
+                        }
+                    }
+                })
+            }
+            Self::Error(err) => write!(fmt, "ERROR: {}", err),
+            Self::MissingAnswer(actual) => write!(fmt, "missing answer (got {})", actual),
+            Self::MissingInput => write!(fmt, "missing input"),
+        }
+    }
+}
+
+// Check is the verification outcome of one part of one day against one input.
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: usize,
+    pub part: Part,
+    pub input: String,
+    pub outcome: Outcome,
+}
+
+// Run a registered solution against each of its known inputs and compare the results with the
+// recorded answers.
+pub fn verify(entry: &Entry, answers: &Answers) -> Vec<Check> {
+    let mut input_names: Vec<&str> = crate::input::INPUT_NAMES.to_vec();
+    answers.input_names(entry.day).into_iter().for_each(|name| {
+        if !input_names.contains(&name) {
+            input_names.push(name);
+        }
+    });
+
+    input_names
+        .into_iter()
+        .flat_map(|name| {
+            let source = entry.input(name);
+            let input = source.read();
+
+            Part::ALL.into_iter().filter_map(move |part| {
+                let expected = answers.get(entry.day, part, name);
+
+                let outcome = match &input {
+                    Ok(input) => Outcome::check(
+                        expected,
+                        (entry.solve)(input, part).map_err(|e| e.in_file(&source.to_string())),
+                    ),
+                    // an input that is neither present nor expected is not worth reporting
+                    Err(_) if expected.is_none() => return None,
+                    Err(_) => Outcome::MissingInput,
+                };
+
+                Some(Check {
+                    day: entry.day,
+                    part,
+                    input: name.to_string(),
+                    outcome,
+                })
+            })
+        })
+        .collect()
+}
+
+mod parser {
+    use crate::{parsers, AocError};
+
+    pub enum Line {
+        Section(usize, usize),
+        Value(String, String),
+    }
+
+    pub fn parse(s: &str) -> Result<Vec<Line>, AocError> {
+        Ok(parser::parse(s)?)
+    }
+
+    peg::parser! {
+        grammar parser() for str {
+            pub rule parse() -> Vec<Line>
+                = ls:line() ** eol()
+                {
+                    ls.into_iter().flatten().collect()
+                }
+
+            rule line() -> Option<Line>
+                = _ l:(section() / value())? _ comment()?
+                {
+                    l
+                }
+
+            rule section() -> Line
+                = "[" _ "day" d:number() "." "part" p:number() _ "]"
+                {
+                    Line::Section(d, p)
+                }
+
+            rule value() -> Line
+                = k:key() _ "=" _ v:(integer() / string())
+                {
+                    Line::Value(k, v)
+                }
+
+            rule key() -> String
+                = k:$(['a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '-']+)
+                {
+                    k.to_string()
+                }
+
+            rule integer() -> String
+                = n:$("-"? ['0'..='9']+)
+                {
+                    n.to_string()
+                }
+
+            rule string() -> String
+                = "\"" cs:character()* "\""
+                {
+                    cs.into_iter().collect()
+                }
+
+            rule character() -> char
+                = "\\n" { '\n' }
+                / "\\\"" { '"' }
+                / "\\\\" { '\\' }
+                / c:$([^ '"' | '\\' | '\n' | '\r'])
+                {?
+                    c.chars().next().ok_or("character")
+                }
+
+            rule number() -> usize
+                = ns:$(['0'..='9']+)
+                {?
+                    parsers::unsigned(ns).or(Err("number"))
+                }
+
+            rule comment()
+                = "#" [^ '\n' | '\r']*
+
+            rule _()
+                = [' ' | '\t']*
+
+            rule eol()
+                = "\r\n"
+                / "\n"
+        }
+    }
+}
+
+#[test]
+fn test_parse() -> Result<(), AocError> {
+    let answers = Answers::parse(
+        "# comment\nversion = 1\n\n[day05.part1]\nsample = 5 # trailing comment\nfull = 7269\n\n[day13.part2]\nsample = \"#.\\n.#\"\n",
+    )?;
+
+    assert_eq!(answers.get(5, Part::One, "sample"), Some("5"));
+    assert_eq!(answers.get(5, Part::One, "full"), Some("7269"));
+    assert_eq!(answers.get(5, Part::Two, "full"), None);
+    assert_eq!(answers.get(13, Part::Two, "sample"), Some("#.\n.#"));
+    assert_eq!(answers.input_names(5), vec!["full", "sample"]);
+
+    assert!(Answers::parse("[day05.part1]\nsample = 5\n").is_err());
+    assert!(Answers::parse("version = 2\n").is_err());
+    assert!(Answers::parse("version = 1\n[day05.part3]\nsample = 5\n").is_err());
+    assert!(Answers::parse("version = 1\nsample = 5\n").is_err());
+    assert!(matches!(
+        Answers::parse("version = 1\n[day05.part1]\nsample = \"#.\n"),
+        Err(AocError::Parse { line: 3, .. })
+    ));
+    assert!(matches!(
+        Answers::parse("version = 1\n[day99999999999999999999.part1]\nsample = 5\n"),
+        Err(AocError::Parse { line: 2, .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_answers_file_covers_registry() -> Result<(), AocError> {
+    let answers = Answers::load(FILE_NAME)?;
+
+    crate::days::registry().iter().for_each(|e| {
+        Part::ALL.into_iter().for_each(|part| {
+            crate::input::INPUT_NAMES
+                .iter()
+                .for_each(|name| assert!(answers.get(e.day, part, name).is_some()))
+        })
+    });
+
+    Ok(())
+}
+
+#[test]
+fn test_outcome_check() {
+    assert_eq!(
+        Outcome::check(Some("5"), Ok(Answer::Number(5))),
+        Outcome::Pass
+    );
+    assert_eq!(
+        Outcome::check(Some("5"), Ok(Answer::Number(6))),
+        Outcome::Fail {
+            expected: "5".to_string(),
+            actual: "6".to_string()
+        }
+    );
+    assert_eq!(
+        Outcome::check(None, Ok(Answer::Number(6))),
+        Outcome::MissingAnswer("6".to_string())
+    );
+}
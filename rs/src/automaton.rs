@@ -0,0 +1,269 @@
+use crate::cycle::History;
+use crate::grid2d::{Grid2D, Neighborhood};
+use crate::grid_nd::Boundary;
+
+// Automaton runs a cellular automaton over a grid.  A step is made of phases that are applied in
+// turn: synchronous rule updates (every cell computed from the previous state of itself and its
+// neighbours), in place updates of every cell and cascades that spread from cell to cell until
+// nothing changes.  Neighbours follow the boundary of the grid, and a Boundary::Default
+// background is updated by synchronous rules like any other cell.
+#[derive(Debug, Clone)]
+pub struct Automaton<T: Copy> {
+    grid: Grid2D<T>,
+    neighborhood: Neighborhood,
+    steps: usize,
+}
+
+impl<T: Copy> Automaton<T> {
+    pub fn new(grid: Grid2D<T>, neighborhood: Neighborhood) -> Self {
+        Self {
+            grid,
+            neighborhood,
+            steps: 0,
+        }
+    }
+
+    pub fn grid(&self) -> &Grid2D<T> {
+        &self.grid
+    }
+
+    pub fn into_grid(self) -> Grid2D<T> {
+        self.grid
+    }
+
+    // The number of steps taken so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    // Take one step made of the phases applied by a function, returning its result.
+    pub fn step<R, F>(&mut self, phases: F) -> R
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        let rv = phases(self);
+        self.steps += 1;
+
+        rv
+    }
+
+    // Take steps until the result of one satisfies a predicate, returning the number of steps
+    // taken so far (so the number of the step that satisfied it when starting from scratch).
+    pub fn run_until<R, F, P>(&mut self, mut phases: F, mut done: P) -> usize
+    where
+        F: FnMut(&mut Self) -> R,
+        P: FnMut(&R) -> bool,
+    {
+        loop {
+            let rv = self.step(&mut phases);
+            if done(&rv) {
+                return self.steps;
+            }
+        }
+    }
+
+    // Update every cell in place.
+    pub fn update<F>(&mut self, mut f: F)
+    where
+        F: FnMut(T) -> T,
+    {
+        self.grid.iter_mut().for_each(|p| *p.value = f(*p.value));
+    }
+
+    // Fire every cell that satisfies a trigger, applying spread to its neighbours, and keep firing
+    // the cells that satisfy the trigger as a result until none are left.  Each cell fires at most
+    // once.  Returns the number of cells that fired.
+    pub fn cascade<P, F>(&mut self, mut trigger: P, mut spread: F) -> usize
+    where
+        P: FnMut(T) -> bool,
+        F: FnMut(T) -> T,
+    {
+        let mut fired = self.grid.map(|_| false);
+        let mut firing: Vec<(usize, usize)> = self
+            .grid
+            .iter()
+            .filter(|p| trigger(p.value))
+            .map(|p| (p.y, p.x))
+            .collect();
+        firing.iter().for_each(|(y, x)| {
+            fired.set_yx(*y, *x, true);
+        });
+
+        let mut rv = firing.len();
+        while let Some((y, x)) = firing.pop() {
+            let neighbors: Vec<_> = self
+                .grid
+                .neighbors(y, x, self.neighborhood)
+                .map(|p| (p.y, p.x))
+                .collect();
+
+            for (y, x) in neighbors {
+                let v = spread(self.grid.get_yx(y, x).unwrap());
+                self.grid.set_yx(y, x, v);
+
+                if trigger(v) && fired.set_yx(y, x, true) == Some(false) {
+                    firing.push((y, x));
+                    rv += 1;
+                }
+            }
+        }
+
+        rv
+    }
+}
+
+impl<T: Copy + PartialEq> Automaton<T> {
+    // Replace every cell (and a Boundary::Default background) by a rule applied to its previous
+    // value and the previous values of its neighbours (in the order of the neighbourhood and
+    // without those beyond the edges of a Boundary::Clip grid).  Returns the number of cells
+    // that changed.
+    pub fn apply<F>(&mut self, mut rule: F) -> usize
+    where
+        F: FnMut(T, &[T]) -> T,
+    {
+        let mut neighbors = vec![];
+        let next = Grid2D::from_fn(self.grid.width(), self.grid.height(), |y, x| {
+            neighbors.clear();
+            neighbors.extend(self.grid.neighbor_values(y, x, self.neighborhood));
+
+            rule(self.grid.get_yx(y, x).unwrap(), &neighbors)
+        });
+
+        let boundary = match self.grid.boundary() {
+            Boundary::Default(v) => {
+                let around = vec![v; self.neighborhood.offsets().len()];
+
+                Boundary::Default(rule(v, &around))
+            }
+            b => b,
+        };
+
+        let rv = self
+            .grid
+            .iter()
+            .zip(next.iter())
+            .filter(|(a, b)| a.value != b.value)
+            .count();
+        self.grid = next.with_boundary(boundary);
+
+        rv
+    }
+
+    // Take synchronous steps of a rule until one changes no cell, returning the number of that
+    // step.
+    pub fn run_until_stable<F>(&mut self, mut rule: F) -> usize
+    where
+        F: FnMut(T, &[T]) -> T,
+    {
+        self.run_until(|a| a.apply(&mut rule), |changed| *changed == 0)
+    }
+}
+
+impl<T: Copy + std::hash::Hash + Eq> Automaton<T> {
+    // Like run_until but gives up (with None) if the grid returns to an earlier state first, as
+    // the steps would then repeat without end (the phases must only depend on the grid).
+    pub fn try_run_until<R, F, P>(&mut self, mut phases: F, mut done: P) -> Option<usize>
+    where
+        F: FnMut(&mut Self) -> R,
+        P: FnMut(&R) -> bool,
+    {
+        let mut history = History::new(self.grid.clone());
+
+        loop {
+            let rv = self.step(&mut phases);
+            if done(&rv) {
+                return Some(self.steps);
+            }
+
+            if history.push(self.grid.clone()).is_some() {
+                return None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+fn life(alive: bool, neighbors: &[bool]) -> bool {
+    let n = neighbors.iter().filter(|v| **v).count();
+
+    n == 3 || (alive && n == 2)
+}
+
+#[cfg(test)]
+fn cells(s: &str) -> Grid2D<bool> {
+    Grid2D::parse_with(s, |ch| Ok(ch == '#')).unwrap()
+}
+
+#[test]
+fn test_life() {
+    let blinker = cells(".....\n..#..\n..#..\n..#..\n.....");
+    let mut automaton = Automaton::new(blinker.clone(), Neighborhood::Eight);
+
+    assert_eq!(automaton.step(|a| a.apply(life)), 4);
+    assert_eq!(
+        automaton
+            .grid()
+            .display(|v| if v { '#' } else { '.' })
+            .to_string(),
+        ".....\n.....\n.###.\n.....\n....."
+    );
+    automaton.step(|a| a.apply(life));
+    assert_eq!(automaton.steps(), 2);
+    assert_eq!(automaton.grid().to_string(), blinker.to_string());
+
+    // a glider on a torus is back where it started after 4 steps for each cell it moves
+    let glider = cells(".#...\n..#..\n###..\n.....\n.....").with_boundary(Boundary::Wrap);
+    let mut automaton = Automaton::new(glider.clone(), Neighborhood::Eight);
+    (0..20).for_each(|_| {
+        automaton.step(|a| a.apply(life));
+    });
+    assert_eq!(automaton.grid().to_string(), glider.to_string());
+
+    // a block is stable
+    let mut automaton = Automaton::new(cells("....\n.##.\n.##.\n...."), Neighborhood::Eight);
+    assert_eq!(automaton.run_until_stable(life), 1);
+
+    // a blinker never dies out
+    let mut automaton = Automaton::new(blinker, Neighborhood::Eight);
+    assert_eq!(automaton.try_run_until(|a| a.apply(life), |_| false), None);
+    assert_eq!(automaton.steps(), 2);
+}
+
+#[test]
+fn test_background() {
+    // every cell without live neighbours comes alive and every other one dies, so the
+    // background flips on every step
+    let rule = |_: bool, neighbors: &[bool]| neighbors.iter().all(|v| !v);
+
+    let grid = cells("#..\n...\n...").with_boundary(Boundary::Default(false));
+    let mut automaton = Automaton::new(grid, Neighborhood::Eight);
+
+    automaton.step(|a| a.apply(rule));
+    assert_eq!(automaton.grid().boundary(), Boundary::Default(true));
+    assert_eq!(
+        automaton
+            .grid()
+            .display(|v| if v { '#' } else { '.' })
+            .to_string(),
+        "#.#\n..#\n###"
+    );
+
+    automaton.step(|a| a.apply(rule));
+    assert_eq!(automaton.grid().boundary(), Boundary::Default(false));
+    assert!(automaton.grid().iter().all(|p| !p.value));
+}
+
+#[test]
+fn test_cascade() {
+    // a single cell over the threshold sets off its neighbours, which set off theirs
+    let grid = Grid2D::new(&[vec![1, 2, 1], vec![2, 3, 0], vec![1, 0, 0]]).unwrap();
+    let mut automaton = Automaton::new(grid, Neighborhood::Four);
+
+    let fired = automaton.step(|a| {
+        a.update(|v| v + 1);
+        a.cascade(|v| v > 3, |v| v + 1)
+    });
+
+    assert_eq!(fired, 4);
+    assert_eq!(automaton.grid().to_string(), "453\n562\n321".to_string());
+}
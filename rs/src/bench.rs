@@ -0,0 +1,210 @@
+use crate::{AocError, Solution};
+use std::time::{Duration, Instant};
+
+// The version of the machine-readable (JSON) report format.
+pub const REPORT_VERSION: usize = 1;
+
+// Timings are the durations of every iteration of a single stage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timings {
+    samples: Vec<Duration>,
+}
+
+impl Timings {
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        Self { samples }
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples.first().copied().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        self.samples
+            .get(self.samples.len() / 2)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples.last().copied().unwrap_or_default()
+    }
+
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"min_ns\":{},\"median_ns\":{},\"max_ns\":{}}}",
+            self.min().as_nanos(),
+            self.median().as_nanos(),
+            self.max().as_nanos()
+        )
+    }
+}
+
+// Stages are the timings of the parse, part1 and part2 stages of a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stages {
+    pub parse: Timings,
+    pub part1: Timings,
+    pub part2: Timings,
+}
+
+impl Stages {
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &Timings)> {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+        .into_iter()
+    }
+}
+
+// Time each stage of a solution separately over a number of iterations.  The parts are timed
+// against an input that was parsed once up front.
+pub fn run<S: Solution>(input: &str, iterations: usize) -> Result<Stages, AocError> {
+    if iterations == 0 {
+        return Err(AocError::invalid_input("iterations must be at least 1"));
+    }
+
+    let input = crate::normalize(input);
+    // the output of each stage goes through black_box so that the work is not optimised away
+    let parse = time(iterations, || {
+        S::parse(&input).map(std::hint::black_box).map(|_| ())
+    })?;
+
+    let parsed = S::parse(&input)?;
+    let part1 = time(iterations, || {
+        S::part1(&parsed).map(std::hint::black_box).map(|_| ())
+    })?;
+    let part2 = time(iterations, || {
+        S::part2(&parsed).map(std::hint::black_box).map(|_| ())
+    })?;
+
+    Ok(Stages {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+fn time<F>(iterations: usize, mut f: F) -> Result<Timings, AocError>
+where
+    F: FnMut() -> Result<(), AocError>,
+{
+    let samples = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            f()?;
+
+            Ok(start.elapsed())
+        })
+        .collect::<Result<Vec<_>, AocError>>()?;
+
+    Ok(Timings::new(samples))
+}
+
+// Report is the benchmark result of one day against one input.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: usize,
+    pub input: String,
+    pub iterations: usize,
+    pub stages: Stages,
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        let stages: Vec<String> = self
+            .stages
+            .iter()
+            .map(|(name, t)| format!("\"{}\":{}", name, t.to_json()))
+            .collect();
+
+        format!(
+            "{{\"day\":{},\"input\":{},\"iterations\":{},{}}}",
+            self.day,
+            json_string(&self.input),
+            self.iterations,
+            stages.join(",")
+        )
+    }
+}
+
+// Render a set of reports as a single JSON document.
+pub fn reports_to_json(reports: &[Report]) -> String {
+    let reports: Vec<String> = reports.iter().map(|r| r.to_json()).collect();
+
+    format!(
+        "{{\"version\":{},\"reports\":[{}]}}",
+        REPORT_VERSION,
+        reports.join(",")
+    )
+}
+
+fn json_string(s: &str) -> String {
+    let mut rv = String::from("\"");
+    s.chars().for_each(|c| match c {
+        '"' => rv.push_str("\\\""),
+        '\\' => rv.push_str("\\\\"),
+        '\n' => rv.push_str("\\n"),
+        c if (c as u32) < 0x20 => rv.push_str(&format!("\\u{:04x}", c as u32)),
+        c => rv.push(c),
+    });
+    rv.push('"');
+
+    rv
+}
+
+#[test]
+fn test_timings() {
+    let timings = Timings::new(vec![
+        Duration::from_nanos(30),
+        Duration::from_nanos(10),
+        Duration::from_nanos(20),
+    ]);
+
+    assert_eq!(timings.min(), Duration::from_nanos(10));
+    assert_eq!(timings.median(), Duration::from_nanos(20));
+    assert_eq!(timings.max(), Duration::from_nanos(30));
+    assert_eq!(
+        timings.to_json(),
+        "{\"min_ns\":10,\"median_ns\":20,\"max_ns\":30}"
+    );
+}
+
+#[test]
+fn test_run() -> Result<(), AocError> {
+    let input = crate::input::InputSource::sample(1).unwrap().read()?;
+    let stages = run::<crate::days::day01::Day01>(&input, 3)?;
+
+    assert!(stages.iter().all(|(_, t)| t.samples.len() == 3));
+    assert!(run::<crate::days::day01::Day01>(&input, 0).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_report_to_json() {
+    let timings = Timings::new(vec![Duration::from_nanos(1)]);
+    let report = Report {
+        day: 1,
+        input: "input/\"day01\".txt".to_string(),
+        iterations: 1,
+        stages: Stages {
+            parse: timings.clone(),
+            part1: timings.clone(),
+            part2: timings,
+        },
+    };
+
+    let t = "{\"min_ns\":1,\"median_ns\":1,\"max_ns\":1}";
+    assert_eq!(
+        reports_to_json(&[report]),
+        format!(
+            "{{\"version\":1,\"reports\":[{{\"day\":1,\"input\":\"input/\\\"day01\\\".txt\",\"iterations\":1,\"parse\":{},\"part1\":{},\"part2\":{}}}]}}",
+            t, t, t
+        )
+    );
+}
@@ -0,0 +1,370 @@
+use aoc::answers::{self, Answers, Outcome};
+use aoc::bench::{self, Report};
+use aoc::days::{self, Entry};
+use aoc::input::InputSource;
+use aoc::{AocError, Part};
+use std::path::Path;
+
+const USAGE: &str = "usage:
+    aoc list
+    aoc run <day> [--part <n>] [--input <input>] [--render <image>]
+    aoc verify [<day>] [--answers <path>]
+    aoc bench [<day>] [--iterations <n>] [--input <input>] [--json]
+    aoc dot <day> [--input <input>]
+
+<input> is 'full' (the default), 'sample', '-' for stdin or a file path.  The 'full' input is read
+from $AOC_INPUT_DIR (or the crate's input directory) and the 'sample' input is built in.
+
+--render writes an image of the puzzle of days that have one (9, 11 and 13) to a .pbm, .pgm or
+.svg file and dot writes the graph of days that have one (12) in the Graphviz DOT language.";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if let Err(err) = run(&args) {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    match args.first().map(|s| s.as_str()) {
+        Some("list") => {
+            days::registry().iter().for_each(|e| {
+                Part::ALL
+                    .iter()
+                    .for_each(|p| println!("day{:02} {}", e.day, p))
+            });
+
+            Ok(())
+        }
+        Some("run") => {
+            let opts = RunOptions::parse(&args[1..])?;
+
+            let entry = find_entry(opts.day)?;
+
+            let parts = match opts.part {
+                Some(part) => vec![part],
+                None => Part::ALL.to_vec(),
+            };
+
+            run_entry(
+                &entry,
+                &parts,
+                opts.input.as_deref(),
+                opts.render.as_deref(),
+            )
+        }
+        Some("verify") => {
+            let opts = VerifyOptions::parse(&args[1..])?;
+
+            let answers = Answers::load(&opts.answers)?;
+
+            let entries: Vec<Entry> = match opts.day {
+                Some(day) => vec![find_entry(day)?],
+                None => days::registry().to_vec(),
+            };
+
+            let (mut passed, mut failed, mut missing) = (0, 0, 0);
+            entries
+                .iter()
+                .flat_map(|e| answers::verify(e, &answers))
+                .for_each(|c| {
+                    match c.outcome {
+                        Outcome::Pass => passed += 1,
+                        Outcome::Fail { .. } | Outcome::Error(_) => failed += 1,
+                        Outcome::MissingAnswer(_) | Outcome::MissingInput => missing += 1,
+                    }
+
+                    println!("day{:02} {} {}: {}", c.day, c.part, c.input, c.outcome);
+                });
+
+            println!("{} passed, {} failed, {} missing", passed, failed, missing);
+
+            if failed > 0 {
+                return Err(Box::new(AocError::invalid_input(
+                    format!("{} verification(s) failed", failed).as_str(),
+                )));
+            }
+
+            Ok(())
+        }
+        Some("bench") => {
+            let opts = BenchOptions::parse(&args[1..])?;
+
+            let entries: Vec<Entry> = match opts.day {
+                Some(day) => vec![find_entry(day)?],
+                None => days::registry().to_vec(),
+            };
+
+            let reports = entries
+                .iter()
+                .map(|e| {
+                    let source = InputSource::from_arg(e.day, &opts.input);
+                    let input = source.read()?;
+                    let stages = (e.bench)(&input, opts.iterations)
+                        .map_err(|err| err.in_file(&source.to_string()))?;
+
+                    Ok(Report {
+                        day: e.day,
+                        input: source.to_string(),
+                        iterations: opts.iterations,
+                        stages,
+                    })
+                })
+                .collect::<Result<Vec<_>, AocError>>()?;
+
+            if opts.json {
+                println!("{}", bench::reports_to_json(&reports));
+            } else {
+                println!(
+                    "{:<5} {:<6} {:>12} {:>12} {:>12}",
+                    "day", "stage", "min", "median", "max"
+                );
+                reports.iter().for_each(|r| {
+                    r.stages.iter().for_each(|(name, t)| {
+                        println!(
+                            "day{:02} {:<6} {:>12} {:>12} {:>12}",
+                            r.day,
+                            name,
+                            format!("{:.1?}", t.min()),
+                            format!("{:.1?}", t.median()),
+                            format!("{:.1?}", t.max())
+                        )
+                    })
+                });
+            }
+
+            Ok(())
+        }
+        Some("dot") => {
+            let opts = DotOptions::parse(&args[1..])?;
+
+            let entry = find_entry(opts.day)?;
+            let source = InputSource::from_arg(entry.day, &opts.input);
+            let input = source.read()?;
+
+            let dot = (entry.dot)(&input)
+                .map_err(|e| e.in_file(&source.to_string()))?
+                .ok_or_else(|| {
+                    AocError::invalid_input(format!("day {} has no graph", entry.day).as_str())
+                })?;
+            print!("{}", dot);
+
+            Ok(())
+        }
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{}", USAGE);
+
+            Ok(())
+        }
+        _ => Err(Box::new(AocError::invalid_input(USAGE))),
+    }
+}
+
+// The registered entry for a day (an error if there is none).
+fn find_entry(day: usize) -> Result<Entry, AocError> {
+    days::find(day).ok_or_else(|| {
+        AocError::invalid_input(format!("no solution registered for day {}", day).as_str())
+    })
+}
+
+fn run_entry(
+    entry: &Entry,
+    parts: &[Part],
+    input: Option<&str>,
+    render: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let source = match input {
+        Some(v) => InputSource::from_arg(entry.day, v),
+        None => InputSource::full(entry.day),
+    };
+    let input = source.read()?;
+
+    parts.iter().try_for_each(|&part| -> Result<(), AocError> {
+        let answer = (entry.solve)(&input, part)
+            .map_err(|e| e.in_file(&source.to_string()))?
+            .to_string();
+        if answer.contains('\n') {
+            println!("day{:02} {}:\n{}", entry.day, part, answer);
+        } else {
+            println!("day{:02} {}: {}", entry.day, part, answer);
+        }
+
+        Ok(())
+    })?;
+
+    if let Some(path) = render {
+        let image = (entry.render)(&input)
+            .map_err(|e| e.in_file(&source.to_string()))?
+            .ok_or_else(|| {
+                AocError::invalid_input(format!("day {} has nothing to render", entry.day).as_str())
+            })?;
+        image.save(Path::new(path))?;
+    }
+
+    Ok(())
+}
+
+struct RunOptions {
+    day: usize,
+    part: Option<Part>,
+    input: Option<String>,
+    render: Option<String>,
+}
+
+impl RunOptions {
+    fn parse(args: &[String]) -> Result<Self, AocError> {
+        let mut args = args.iter();
+
+        let day = match args.next() {
+            Some(v) => parse_number("day", v)?,
+            None => return Err(AocError::invalid_input(USAGE)),
+        };
+
+        let mut opts = Self {
+            day,
+            part: None,
+            input: None,
+            render: None,
+        };
+
+        while let Some(arg) = args.next() {
+            match (arg.as_str(), args.next()) {
+                ("--part" | "-p", Some(v)) => opts.part = Some(parse_part(v)?),
+                ("--input" | "-i", Some(v)) => opts.input = Some(v.clone()),
+                ("--render" | "-r", Some(v)) => opts.render = Some(v.clone()),
+                _ => {
+                    return Err(AocError::invalid_input(
+                        format!("unexpected argument '{}'\n{}", arg, USAGE).as_str(),
+                    ))
+                }
+            }
+        }
+
+        Ok(opts)
+    }
+}
+
+struct VerifyOptions {
+    day: Option<usize>,
+    answers: String,
+}
+
+impl VerifyOptions {
+    fn parse(args: &[String]) -> Result<Self, AocError> {
+        let mut args = args.iter().peekable();
+
+        let mut opts = Self {
+            day: None,
+            answers: answers::FILE_NAME.to_string(),
+        };
+
+        if let Some(v) = args.next_if(|v| !v.starts_with('-')) {
+            opts.day = Some(parse_number("day", v)?);
+        }
+
+        while let Some(arg) = args.next() {
+            match (arg.as_str(), args.next()) {
+                ("--answers" | "-a", Some(v)) => opts.answers = v.clone(),
+                _ => {
+                    return Err(AocError::invalid_input(
+                        format!("unexpected argument '{}'\n{}", arg, USAGE).as_str(),
+                    ))
+                }
+            }
+        }
+
+        Ok(opts)
+    }
+}
+
+struct BenchOptions {
+    day: Option<usize>,
+    iterations: usize,
+    input: String,
+    json: bool,
+}
+
+impl BenchOptions {
+    fn parse(args: &[String]) -> Result<Self, AocError> {
+        let mut args = args.iter().peekable();
+
+        let mut opts = Self {
+            day: None,
+            iterations: 10,
+            input: "full".to_string(),
+            json: false,
+        };
+
+        if let Some(v) = args.next_if(|v| !v.starts_with('-')) {
+            opts.day = Some(parse_number("day", v)?);
+        }
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--json" => opts.json = true,
+                "--iterations" | "-n" => match args.next() {
+                    Some(v) => opts.iterations = parse_number("iterations", v)?,
+                    None => return Err(AocError::invalid_input(USAGE)),
+                },
+                "--input" | "-i" => match args.next() {
+                    Some(v) => opts.input = v.clone(),
+                    None => return Err(AocError::invalid_input(USAGE)),
+                },
+                _ => {
+                    return Err(AocError::invalid_input(
+                        format!("unexpected argument '{}'\n{}", arg, USAGE).as_str(),
+                    ))
+                }
+            }
+        }
+
+        Ok(opts)
+    }
+}
+
+struct DotOptions {
+    day: usize,
+    input: String,
+}
+
+impl DotOptions {
+    fn parse(args: &[String]) -> Result<Self, AocError> {
+        let mut args = args.iter();
+
+        let day = match args.next() {
+            Some(v) => parse_number("day", v)?,
+            None => return Err(AocError::invalid_input(USAGE)),
+        };
+
+        let mut opts = Self {
+            day,
+            input: "full".to_string(),
+        };
+
+        while let Some(arg) = args.next() {
+            match (arg.as_str(), args.next()) {
+                ("--input" | "-i", Some(v)) => opts.input = v.clone(),
+                _ => {
+                    return Err(AocError::invalid_input(
+                        format!("unexpected argument '{}'\n{}", arg, USAGE).as_str(),
+                    ))
+                }
+            }
+        }
+
+        Ok(opts)
+    }
+}
+
+fn parse_number(name: &str, value: &str) -> Result<usize, AocError> {
+    value
+        .parse()
+        .map_err(|_| AocError::invalid_input(format!("invalid {} '{}'", name, value).as_str()))
+}
+
+fn parse_part(value: &str) -> Result<Part, AocError> {
+    Part::from_number(parse_number("part", value)?)
+        .ok_or_else(|| AocError::invalid_input(format!("invalid part '{}'", value).as_str()))
+}
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Helpers for simulations whose states eventually repeat: the states are recorded as the
+// simulation runs so that the first repeated state is noticed, after which the state at any later
+// step can be found without simulating the steps in between.
+
+// Cycle is the shape of a sequence of states that repeats: the states from step start onwards
+// repeat every period steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    // The first step with the same state as a step.
+    pub fn reduce(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.period
+        }
+    }
+}
+
+// History is the sequence of states of a simulation from step 0 up to the first repeated state.
+#[derive(Debug, Clone)]
+pub struct History<S> {
+    states: Vec<S>,
+    steps: HashMap<S, usize>,
+    cycle: Option<Cycle>,
+}
+
+impl<S: Clone + Hash + Eq> History<S> {
+    pub fn new(initial: S) -> Self {
+        Self {
+            steps: HashMap::from([(initial.clone(), 0)]),
+            states: vec![initial],
+            cycle: None,
+        }
+    }
+
+    // The number of steps recorded (not including the repeated state of a cycle).
+    pub fn steps(&self) -> usize {
+        self.states.len() - 1
+    }
+
+    // The state of the last step recorded.
+    pub fn last(&self) -> &S {
+        &self.states[self.states.len() - 1]
+    }
+
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    // Record the state of the next step, returning the cycle once a state repeats (nothing more is
+    // recorded after that).
+    pub fn push(&mut self, state: S) -> Option<Cycle> {
+        if self.cycle.is_some() {
+            return self.cycle;
+        }
+
+        match self.steps.get(&state) {
+            Some(start) => {
+                self.cycle = Some(Cycle {
+                    start: *start,
+                    period: self.states.len() - start,
+                });
+            }
+            None => {
+                self.steps.insert(state.clone(), self.states.len());
+                self.states.push(state);
+            }
+        }
+
+        self.cycle
+    }
+
+    // The state at a step, which is known if it has been recorded or lies on the cycle.
+    pub fn state_at(&self, step: usize) -> Option<&S> {
+        match self.cycle {
+            Some(cycle) => self.states.get(cycle.reduce(step)),
+            None => self.states.get(step),
+        }
+    }
+}
+
+// Run a simulation from a state until a state repeats, giving up (with None) after a number of
+// steps.
+pub fn find_cycle<S, F>(initial: S, limit: usize, mut step: F) -> Option<Cycle>
+where
+    S: Clone + Hash + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut history = History::new(initial);
+
+    (0..limit).find_map(|_| {
+        let next = step(history.last());
+        history.push(next)
+    })
+}
+
+// The state of a simulation after a number of steps, skipping ahead once a state repeats.
+pub fn state_at<S, F>(initial: S, steps: usize, mut step: F) -> S
+where
+    S: Clone + Hash + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut history = History::new(initial);
+    while history.steps() < steps {
+        let next = step(history.last());
+        if history.push(next).is_some() {
+            break;
+        }
+    }
+
+    history.state_at(steps).unwrap().clone()
+}
+
+#[test]
+fn test_cycle() {
+    // 7, 3, 4, 0, 1, 2, 3, ...
+    let step = |v: &usize| (v + 1) % 5;
+
+    let mut history = History::new(7);
+    (0..5).for_each(|_| assert_eq!(history.push(step(history.last())), None));
+    assert_eq!(history.steps(), 5);
+    assert_eq!(history.state_at(6), None);
+
+    let cycle = Cycle {
+        start: 1,
+        period: 5,
+    };
+    assert_eq!(history.push(step(history.last())), Some(cycle));
+    assert_eq!(history.steps(), 5);
+    assert_eq!(history.state_at(0), Some(&7));
+    assert_eq!(history.state_at(6), Some(&3));
+    assert_eq!(cycle.reduce(1_000_000_000_000), 5);
+
+    assert_eq!(find_cycle(7, 10, step), Some(cycle));
+    assert_eq!(find_cycle(7, 5, step), None);
+    assert_eq!(state_at(7, 1_000_000_000_000, step), 2);
+    assert_eq!(state_at(7, 3, step), 0);
+    assert_eq!(state_at(7, 0, step), 7);
+}
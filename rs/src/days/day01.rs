@@ -0,0 +1,49 @@
+use crate::{Answer, AocError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: usize = 1;
+
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse(input))
+    }
+
+    fn part1(values: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(values).into())
+    }
+
+    fn part2(values: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(values).into())
+    }
+}
+
+fn parse(input: &str) -> Vec<usize> {
+    input.lines().filter_map(|v| v.parse().ok()).collect()
+}
+
+fn part1(values: &[usize]) -> usize {
+    count_increases(values)
+}
+
+fn part2(values: &[usize]) -> usize {
+    let values: Vec<usize> = values.windows(3).map(|v| v.iter().sum()).collect();
+
+    count_increases(&values)
+}
+
+fn count_increases(values: &[usize]) -> usize {
+    values.windows(2).filter(|vs| vs[0] < vs[1]).count()
+}
+
+#[test]
+fn test_simulate_sample() -> Result<(), Box<dyn std::error::Error>> {
+    let input = crate::input::InputSource::sample(1).unwrap().read()?;
+    let values = parse(&input);
+    assert_eq!(part1(&values), 7);
+    assert_eq!(part2(&values), 5);
+
+    Ok(())
+}
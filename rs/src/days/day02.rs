@@ -0,0 +1,106 @@
+use crate::parsers;
+use crate::{Answer, AocError, Solution};
+use std::ops::Add;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: usize = 2;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(instrs: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(instrs).into())
+    }
+
+    fn part2(instrs: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(instrs).into())
+    }
+}
+
+fn parse(data: &str) -> Result<Vec<Instruction>, AocError> {
+    parsers::lines(data, |l| {
+        let (command, n) = l
+            .split_once(' ')
+            .ok_or_else(|| AocError::invalid_input("expected a command and a distance"))?;
+        let n = parsers::unsigned(n.trim_start())? as isize;
+
+        match command {
+            "forward" => Ok(Instruction(0, n, 0)),
+            "up" => Ok(Instruction(0, 0, -n)),
+            "down" => Ok(Instruction(0, 0, n)),
+            _ => Err(AocError::invalid_input(
+                format!("unknown command {}", command).as_str(),
+            )),
+        }
+    })
+}
+
+fn part1(instrs: &[Instruction]) -> isize {
+    let pos = instrs.iter().fold(Instruction(0, 0, 0), |acc, v| acc + *v);
+
+    pos.y() * pos.z()
+}
+
+fn part2(instrs: &[Instruction]) -> isize {
+    let pos = instrs.iter().fold(Position(0, 0, 0), |p, i| p.adjust(*i));
+
+    pos.horizontal() * pos.depth()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction(isize, isize, isize);
+
+impl Add for Instruction {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0, self.1 + other.1, self.2 + other.2)
+    }
+}
+
+impl Instruction {
+    fn y(&self) -> isize {
+        self.1
+    }
+
+    fn z(&self) -> isize {
+        self.2
+    }
+}
+
+// Position is (horizontal, depth, aim)
+#[derive(Debug)]
+struct Position(isize, isize, isize);
+
+impl Position {
+    fn adjust(self, other: Instruction) -> Self {
+        match other {
+            Instruction(_, 0, n) => Self(self.0, self.1, self.2 + n),
+            Instruction(_, n, 0) => Self(self.0 + n, self.1 + (self.2 * n), self.2),
+            _ => self,
+        }
+    }
+
+    fn horizontal(&self) -> isize {
+        self.0
+    }
+
+    fn depth(&self) -> isize {
+        self.1
+    }
+}
+
+#[test]
+fn test_simulate_sample() -> Result<(), Box<dyn std::error::Error>> {
+    let input = crate::input::InputSource::sample(2).unwrap().read()?;
+    let instrs = parse(&input)?;
+    assert_eq!(part1(&instrs), 150);
+    assert_eq!(part2(&instrs), 900);
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use crate::parsers;
+use crate::{Answer, AocError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: usize = 3;
+
+    type Input = Vec<Code>;
+
+    // Parse the input into a list of lists of bools (not 1's and 0's)
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(codes: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(codes).into())
+    }
+
+    fn part2(codes: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(codes).into())
+    }
+}
+
+fn parse(data: &str) -> Result<Vec<Code>, AocError> {
+    parsers::lines(data, |l| {
+        l.chars()
+            .map(|ch| match ch {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                _ => Err(AocError::invalid_input("expected a binary digit")),
+            })
+            .collect()
+    })
+}
+
+fn part1(codes: &[Code]) -> usize {
+    let code_len = codes[0].len();
+    let codes_mid = (codes.len() / 2) as f32;
+
+    // Gamma Rate is the most common value for the corresponding position found by summing the true
+    // values and comparing the resulting sum against the middle "number of values".
+    // NOTE: This is a SIMD approach to the "most common" (or majority) value per position.
+    let gamma = codes
+        .iter()
+        .fold(vec![0; code_len], |acc, v| {
+            acc.iter()
+                .zip(v.iter())
+                .map(|(sum, v)| sum + if *v { 1 } else { 0 })
+                .collect::<Vec<_>>()
+        })
+        .into_iter()
+        .map(|v| v as f32 > codes_mid)
+        .collect::<Vec<_>>();
+
+    let gamma_int = rate_to_usize(&gamma);
+
+    // Epsilon Rate is the least common value for the corresponding position found by inverting
+    // the result of the Gamma Rate.
+    let epsilon = gamma.iter().map(|&v| !v).collect::<Vec<_>>();
+
+    let epsilon_int = rate_to_usize(&epsilon);
+
+    gamma_int * epsilon_int
+}
+
+fn part2(codes: &[Code]) -> usize {
+    // Oxygen Generator Rate reduces the list of codes based on the most common value in a position
+    // (defaulting to 1's if the 1's and 0's counts are equal) followed by the codes that match
+    // that value in the subsequent position until all positions are processed and only one value
+    // remains.
+    // NOTE: This is a SISD approach to the "most common" (or majority) value per position.
+    let mut oxygen_codes = codes.to_vec();
+    (0..codes[0].len()).for_each(|i| {
+        if oxygen_codes.len() <= 1 {
+            return;
+        }
+
+        let v = calc_codes_majority_value_for_pos(&oxygen_codes, i);
+
+        oxygen_codes.retain(|code| code[i] == v);
+    });
+
+    let oxygen_int = rate_to_usize(&oxygen_codes[0]);
+
+    // CO2 Scrubber Rate is basically the inverse of the Oxygen Generator Rate using the least
+    // common value per position (and defaulting to 0 if counts are equal).
+    let mut co2_scrubber_codes = codes.to_vec();
+    (0..codes[0].len()).for_each(|i| {
+        if co2_scrubber_codes.len() <= 1 {
+            return;
+        }
+
+        let v = !calc_codes_majority_value_for_pos(&co2_scrubber_codes, i);
+
+        co2_scrubber_codes.retain(|code| code[i] == v);
+    });
+
+    let co2_scrubber_int = rate_to_usize(&co2_scrubber_codes[0]);
+
+    oxygen_int * co2_scrubber_int
+}
+
+type Code = Vec<bool>;
+
+fn calc_codes_majority_value_for_pos(codes: &[Code], pos: usize) -> bool {
+    let codes_mid = codes.len() as f32 / 2_f32;
+    let count = codes.iter().filter(|code| code[pos]).count();
+
+    count as f32 >= codes_mid
+}
+
+fn rate_to_usize(rate: &[bool]) -> usize {
+    rate.iter().fold(0, |acc, &v| (acc << 1) | usize::from(v))
+}
+
+#[test]
+fn test_simulate_sample() -> Result<(), Box<dyn std::error::Error>> {
+    let input = crate::input::InputSource::sample(3).unwrap().read()?;
+    let codes = parse(&input)?;
+    assert_eq!(part1(&codes), 198);
+    assert_eq!(part2(&codes), 230);
+
+    Ok(())
+}
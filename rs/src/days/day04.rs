@@ -0,0 +1,126 @@
+use crate::grid2d::Grid2D;
+use crate::{parsers, Answer, AocError, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: usize = 4;
+
+    type Input = (Vec<isize>, Vec<Board>);
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        // Parse the input into the draws (a list of numbers) and a list of boards (which are
+        // structs with a single "cells" field that is a list of lists of numbers).
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        let (draws, boards) = input;
+
+        match part1(draws, boards.clone()) {
+            Some((draw, score)) => Ok((draw * score).into()),
+            None => Err(AocError::unsolvable("no board reached bingo")),
+        }
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        let (draws, boards) = input;
+
+        match part2(draws, boards.clone()) {
+            Some((draw, score)) => Ok((draw * score).into()),
+            None => Err(AocError::unsolvable("no board reached bingo")),
+        }
+    }
+}
+
+fn parse(data: &str) -> Result<(Vec<isize>, Vec<Board>), AocError> {
+    let sections = parsers::sections(data);
+    let (draws, boards) = sections
+        .split_first()
+        .ok_or_else(|| AocError::invalid_input("missing draws"))?;
+
+    // the numbers are unsigned as a marked cell is set to -1
+    let draws = draws
+        .parse(parsers::unsigned_list)?
+        .into_iter()
+        .map(|v| v as isize)
+        .collect();
+    let boards = boards
+        .iter()
+        .map(|b| b.parse(parsers::unsigned_table).and_then(Board::init))
+        .collect::<Result<_, _>>()?;
+
+    Ok((draws, boards))
+}
+
+#[derive(Debug, Clone)]
+pub struct Board {
+    cells: Grid2D<isize>,
+}
+
+impl Board {
+    fn init(rs: Vec<Vec<usize>>) -> Result<Board, AocError> {
+        Ok(Board {
+            cells: Grid2D::new(&rs)?.map(|v| v as isize),
+        })
+    }
+
+    fn mark_cells(&mut self, v: &isize) {
+        self.cells
+            .iter_mut()
+            .filter(|p| *p.value == *v)
+            .for_each(|p| *p.value = -1);
+    }
+
+    fn has_bingo(&self) -> bool {
+        self.cells.rows().any(|mut r| r.all(|v| v < 0))
+            || self.cells.cols().any(|mut c| c.all(|v| v < 0))
+    }
+
+    fn get_score(&self) -> isize {
+        self.cells.iter().map(|p| p.value).filter(|v| *v > 0).sum()
+    }
+}
+
+fn part1(draws: &[isize], mut boards: Vec<Board>) -> Option<(isize, isize)> {
+    for draw in draws {
+        for board in boards.iter_mut() {
+            board.mark_cells(draw);
+            if board.has_bingo() {
+                return Some((*draw, board.get_score()));
+            }
+        }
+    }
+
+    None
+}
+
+fn part2(draws: &[isize], mut boards: Vec<Board>) -> Option<(isize, isize)> {
+    let mut board_status = std::collections::HashMap::<usize, (usize, isize)>::new();
+
+    for (di, draw) in draws.iter().enumerate() {
+        for (bi, board) in boards.iter_mut().enumerate() {
+            board.mark_cells(draw);
+            if board.has_bingo() {
+                board_status.entry(bi).or_insert((di, board.get_score()));
+            }
+        }
+    }
+
+    let last_board = board_status
+        .iter()
+        .max_by(|(_, (di_a, _)), (_, (di_b, _))| di_a.cmp(di_b));
+
+    if let Some((_, (di, score))) = last_board {
+        Some((draws[*di], *score))
+    } else {
+        None
+    }
+}
+
+#[test]
+fn test_parse_rejects_negative_numbers() {
+    assert!(parse("7,-1,9\n\n 1  2\n 3  4").is_err());
+    assert!(parse("7,1,9\n\n 1 -2\n 3  4").is_err());
+    assert!(parse("7,1,9\n\n 1  2\n 3  4").is_ok());
+}
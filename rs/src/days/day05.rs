@@ -0,0 +1,241 @@
+use crate::parsers;
+use crate::pos::Pos;
+use crate::sparse_grid::SparseGrid;
+use crate::{Answer, AocError, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: usize = 5;
+
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        // Parse the input into a list of Line instances where each contains two Points consisting
+        // of an x and a y coordinate.
+        parse(input)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer, AocError> {
+        match part1(lines) {
+            Some(n) => Ok(n.into()),
+            None => Err(AocError::invalid_input(
+                "a line is not horizontal, vertical or diagonal",
+            )),
+        }
+    }
+
+    fn part2(lines: &Self::Input) -> Result<Answer, AocError> {
+        match part2(lines) {
+            Some(n) => Ok(n.into()),
+            None => Err(AocError::invalid_input(
+                "a line is not horizontal, vertical or diagonal",
+            )),
+        }
+    }
+}
+
+fn parse(data: &str) -> Result<Vec<Line>, AocError> {
+    parsers::lines(data, |l| {
+        let (p1, p2) = parsers::point_pair(l)?;
+
+        Ok(Line(p1, p2))
+    })
+}
+
+#[derive(Debug)]
+pub struct Line(Pos, Pos);
+
+impl Line {
+    fn is_vertical(&self) -> bool {
+        self.0.x == self.1.x
+    }
+
+    fn is_horizontal(&self) -> bool {
+        self.0.y == self.1.y
+    }
+}
+
+#[test]
+fn test_line_is_vertical() {
+    struct TestCase {
+        data: Line,
+        expected: bool,
+    }
+    let tests = [
+        TestCase {
+            data: Line(Pos::new(1, 1), Pos::new(1, 3)),
+            expected: true,
+        },
+        TestCase {
+            data: Line(Pos::new(1, 1), Pos::new(3, 1)),
+            expected: false,
+        },
+        TestCase {
+            data: Line(Pos::new(1, 1), Pos::new(3, 3)),
+            expected: false,
+        },
+    ];
+
+    tests
+        .iter()
+        .for_each(|t| assert_eq!((t.data).is_vertical(), t.expected));
+}
+
+#[test]
+fn test_line_is_horizontal() {
+    struct TestCase {
+        data: Line,
+        expected: bool,
+    }
+    let tests = [
+        TestCase {
+            data: Line(Pos::new(1, 1), Pos::new(1, 3)),
+            expected: false,
+        },
+        TestCase {
+            data: Line(Pos::new(1, 1), Pos::new(3, 1)),
+            expected: true,
+        },
+        TestCase {
+            data: Line(Pos::new(1, 1), Pos::new(3, 3)),
+            expected: false,
+        },
+    ];
+
+    tests
+        .iter()
+        .for_each(|t| assert_eq!((t.data).is_horizontal(), t.expected));
+}
+
+#[test]
+fn test_point_to() {
+    struct TestCase {
+        point: Pos,
+        other: Pos,
+        expected: Vec<Pos>,
+    }
+    let tests = [
+        TestCase {
+            // vertical+N
+            point: Pos::new(1, 1),
+            other: Pos::new(1, 3),
+            expected: vec![Pos::new(1, 1), Pos::new(1, 2), Pos::new(1, 3)],
+        },
+        TestCase {
+            // horizontal+E
+            point: Pos::new(1, 1),
+            other: Pos::new(3, 1),
+            expected: vec![Pos::new(1, 1), Pos::new(2, 1), Pos::new(3, 1)],
+        },
+        TestCase {
+            // vertical+S
+            point: Pos::new(1, 3),
+            other: Pos::new(1, 1),
+            expected: vec![Pos::new(1, 3), Pos::new(1, 2), Pos::new(1, 1)],
+        },
+        TestCase {
+            // horizontal+W
+            point: Pos::new(3, 1),
+            other: Pos::new(1, 1),
+            expected: vec![Pos::new(3, 1), Pos::new(2, 1), Pos::new(1, 1)],
+        },
+        TestCase {
+            // diagonal+NE
+            point: Pos::new(1, 1),
+            other: Pos::new(3, 3),
+            expected: vec![Pos::new(1, 1), Pos::new(2, 2), Pos::new(3, 3)],
+        },
+        TestCase {
+            // diagnoal+SE
+            point: Pos::new(1, 3),
+            other: Pos::new(3, 1),
+            expected: vec![Pos::new(1, 3), Pos::new(2, 2), Pos::new(3, 1)],
+        },
+        TestCase {
+            // diagonal+SW
+            point: Pos::new(3, 3),
+            other: Pos::new(1, 1),
+            expected: vec![Pos::new(3, 3), Pos::new(2, 2), Pos::new(1, 1)],
+        },
+        TestCase {
+            // diagnoal+NW
+            point: Pos::new(3, 1),
+            other: Pos::new(1, 3),
+            expected: vec![Pos::new(3, 1), Pos::new(2, 2), Pos::new(1, 3)],
+        },
+    ];
+
+    tests.iter().for_each(|t| {
+        let points: Option<Vec<Pos>> = t.point.line_to(t.other).map(|l| l.collect());
+        assert_eq!(points.as_ref(), Some(&t.expected));
+    });
+}
+
+fn part1(lines: &[Line]) -> Option<usize> {
+    let mut map = SparseGrid::<usize>::new();
+
+    // every line must be horizontal, vertical or diagonal
+    lines
+        .iter()
+        .filter(|l| l.is_vertical() || l.is_horizontal())
+        .map(|l| l.0.line_to(l.1))
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .for_each(|pt| *map.get_mut_or_insert(pt, 0) += 1);
+
+    Some(map.iter().filter(|p| p.value > 1).count())
+}
+
+#[test]
+fn test_part1_sample() -> Result<(), Box<dyn std::error::Error>> {
+    let input = crate::input::InputSource::sample(5).unwrap().read()?;
+    let lines = parse(&input)?;
+    assert_eq!(part1(&lines), Some(5));
+
+    Ok(())
+}
+
+#[test]
+fn test_part1_full() -> Result<(), Box<dyn std::error::Error>> {
+    let input = crate::input::InputSource::full(5).read()?;
+    let lines = parse(&input)?;
+    assert_eq!(part1(&lines), Some(7269));
+
+    Ok(())
+}
+
+fn part2(lines: &[Line]) -> Option<usize> {
+    let mut map = SparseGrid::<usize>::new();
+
+    // every line must be horizontal, vertical or diagonal
+    lines
+        .iter()
+        // do not filter any lines
+        .map(|l| l.0.line_to(l.1))
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .for_each(|pt| *map.get_mut_or_insert(pt, 0) += 1);
+
+    Some(map.iter().filter(|p| p.value > 1).count())
+}
+
+#[test]
+fn test_part2_sample() -> Result<(), Box<dyn std::error::Error>> {
+    let input = crate::input::InputSource::sample(5).unwrap().read()?;
+    let lines = parse(&input)?;
+    assert_eq!(part2(&lines), Some(12));
+
+    Ok(())
+}
+
+#[test]
+fn test_part2_full() -> Result<(), Box<dyn std::error::Error>> {
+    let input = crate::input::InputSource::full(5).read()?;
+    let lines = parse(&input)?;
+    assert_eq!(part2(&lines), Some(21140));
+
+    Ok(())
+}
@@ -1,19 +1,27 @@
-use aoc;
+use crate::{Answer, AocError, Solution};
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = aoc::read_file("input/day06.txt")?;
-    let data = parse(&input)?;
-    println!("part1: {:?}", simulate(data.clone(), 80));
-    println!("part2: {:?}", simulate(data.clone(), 256));
+pub struct Day06;
 
-    Ok(())
+impl Solution for Day06 {
+    const DAY: usize = 6;
+
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(simulate(data.clone(), 80).into())
+    }
+
+    fn part2(data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(simulate(data.clone(), 256).into())
+    }
 }
 
-fn parse(input: &str) -> Result<Vec<usize>, Box<dyn std::error::Error>> {
-    let values: Vec<usize> = input
-        .split(',')
-        .map(|s| s.parse())
-        .collect::<Result<_, _>>()?;
+fn parse(input: &str) -> Result<Vec<usize>, AocError> {
+    let values = crate::parsers::unsigned_list(input)?;
 
     let mut dist = vec![0; 9];
     values.into_iter().for_each(|v| dist[v] += 1);
@@ -24,7 +32,7 @@ fn parse(input: &str) -> Result<Vec<usize>, Box<dyn std::error::Error>> {
 fn simulate(mut dist: Vec<usize>, nr_days: usize) -> usize {
     for _ in 0..nr_days {
         let nr_spawning = dist[0];
-        (0..8).into_iter().for_each(|i| dist[i] = dist[i + 1]);
+        (0..8).for_each(|i| dist[i] = dist[i + 1]);
         dist[6] += nr_spawning;
         dist[8] = nr_spawning;
     }
@@ -34,7 +42,7 @@ fn simulate(mut dist: Vec<usize>, nr_days: usize) -> usize {
 
 #[test]
 fn test_simulate_sample() -> Result<(), Box<dyn std::error::Error>> {
-    let input = aoc::read_file("input/day06.test.txt")?;
+    let input = crate::input::InputSource::sample(6).unwrap().read()?;
     let fish = parse(&input)?;
     assert_eq!(simulate(fish.clone(), 1), 5);
     assert_eq!(simulate(fish.clone(), 2), 6);
@@ -49,7 +57,7 @@ fn test_simulate_sample() -> Result<(), Box<dyn std::error::Error>> {
 
 #[test]
 fn test_simulate_full() -> Result<(), Box<dyn std::error::Error>> {
-    let input = aoc::read_file("input/day06.txt")?;
+    let input = crate::input::InputSource::full(6).read()?;
     let fish = parse(&input)?;
     assert_eq!(simulate(fish.clone(), 80), 371379);
     assert_eq!(simulate(fish.clone(), 256), 1674303997472);
@@ -1,22 +1,30 @@
-use aoc;
+use crate::{Answer, AocError, Solution};
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = aoc::read_file("input/day07.txt")?;
-    let data = parse(&input)?;
-    println!("part1: {:?}", part1(data.clone()));
-    println!("part2: {:?}", part2(data.clone()));
+pub struct Day07;
 
-    Ok(())
+impl Solution for Day07 {
+    const DAY: usize = 7;
+
+    type Input = Data;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(data)?.into())
+    }
+
+    fn part2(data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(data)?.into())
+    }
 }
 
 // Data represents a distribution of horizontal position to count at that position
 type Data = std::collections::HashMap<usize, usize>;
 
-fn parse(data: &str) -> Result<Data, Box<dyn std::error::Error>> {
-    let values: Vec<usize> = data
-        .split(',')
-        .map(|s| s.parse())
-        .collect::<Result<_, _>>()?;
+fn parse(data: &str) -> Result<Data, AocError> {
+    let values = crate::parsers::unsigned_list(data)?;
 
     let mut dist = Data::new();
     values.into_iter().for_each(|v| {
@@ -27,75 +35,55 @@ fn parse(data: &str) -> Result<Data, Box<dyn std::error::Error>> {
     Ok(dist)
 }
 
-fn part1(data: Data) -> Result<usize, Box<dyn std::error::Error>> {
-    let optimal_alignment = align(&data, |v| v)?;
+fn part1(data: &Data) -> Result<usize, AocError> {
+    let optimal_alignment = align(data, |v| v)?;
 
     Ok(optimal_alignment.1)
 }
 
-fn part2(data: Data) -> Result<usize, Box<dyn std::error::Error>> {
-    let optimal_alignment = align(&data, |v| (v * (v + 1)) / 2)?;
+fn part2(data: &Data) -> Result<usize, AocError> {
+    let optimal_alignment = align(data, |v| (v * (v + 1)) / 2)?;
 
     Ok(optimal_alignment.1)
 }
 
-fn align(data: &Data, fuel_cost: fn(usize) -> usize) -> Result<(usize, usize), Error> {
+fn align(data: &Data, fuel_cost: fn(usize) -> usize) -> Result<(usize, usize), AocError> {
     let min = data.keys().min();
-    if let None = min {
-        return Err(Error::new("failed to yield a minimum position from data"));
+    if min.is_none() {
+        return Err(AocError::invalid_input(
+            "failed to yield a minimum position from data",
+        ));
     }
 
     let max = data.keys().max();
-    if let None = max {
-        return Err(Error::new("failed to yield a maximum position from data"));
+    if max.is_none() {
+        return Err(AocError::invalid_input(
+            "failed to yield a maximum position from data",
+        ));
     }
 
     let result = (*(min.unwrap())..=*(max.unwrap()))
-        .into_iter()
         .map(|i| {
             let fuel: usize = data
                 .iter()
-                .map(|(p, c)| fuel_cost((*p as isize - i as isize).abs() as usize) * *c)
+                .map(|(p, c)| fuel_cost((*p as isize - i as isize).unsigned_abs()) * *c)
                 .sum();
 
             (i, fuel)
         })
         .min_by_key(|v| v.1);
-    if let None = result {
-        return Err(Error::new("failed to yield an optimal position from data"));
+    if result.is_none() {
+        return Err(AocError::invalid_input(
+            "failed to yield an optimal position from data",
+        ));
     }
 
     Ok(result.unwrap())
 }
 
-#[derive(Debug)]
-struct Error {
-    details: String,
-}
-
-impl Error {
-    fn new(msg: &str) -> Error {
-        Error {
-            details: msg.to_string(),
-        }
-    }
-}
-
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.details)
-    }
-}
-
-impl std::error::Error for Error {
-    fn description(&self) -> &str {
-        &self.details
-    }
-}
-
 #[test]
 fn test_align() {
-    let input = aoc::read_file("input/day07.test.txt");
+    let input = crate::input::InputSource::sample(7).unwrap().read();
     assert!(input.is_ok());
     let input = input.unwrap();
 
@@ -116,7 +104,7 @@ fn test_align() {
 
 #[test]
 fn test_simulate_sample() {
-    let input = aoc::read_file("input/day07.test.txt");
+    let input = crate::input::InputSource::sample(7).unwrap().read();
     assert!(input.is_ok());
     let input = input.unwrap();
 
@@ -125,18 +113,18 @@ fn test_simulate_sample() {
     let data = data.unwrap();
     // println!("{:#?}", data);
 
-    let result = part1(data.clone());
+    let result = part1(&data);
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), 37);
 
-    let result = part2(data.clone());
+    let result = part2(&data);
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), 168);
 }
 
 #[test]
 fn test_simulate_full() {
-    let input = aoc::read_file("input/day07.txt");
+    let input = crate::input::InputSource::full(7).read();
     assert!(input.is_ok());
     let input = input.unwrap();
 
@@ -144,11 +132,11 @@ fn test_simulate_full() {
     assert!(data.is_ok());
     let data = data.unwrap();
 
-    let result = part1(data.clone());
+    let result = part1(&data);
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), 349357);
 
-    let result = part2(data.clone());
+    let result = part2(&data);
     assert!(result.is_ok());
     assert_eq!(result.unwrap(), 96708205);
 }
@@ -1,65 +1,48 @@
-use aoc;
+use crate::parsers;
+use crate::{Answer, AocError, Solution};
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = aoc::read_file("input/day08.txt")?;
-    let data = parse(&input)?;
-    println!("part1: {:?}", part1(&data));
-    println!("part2: {:?}", part2(&data));
+pub struct Day08;
 
-    Ok(())
-}
+impl Solution for Day08 {
+    const DAY: usize = 8;
 
-fn parse(data: &str) -> Result<Data, parser::ParseError> {
-    parser::parse(&data)
-}
+    type Input = Data;
 
-mod parser {
-    use crate::{Data, Pattern, Scenario, Segment};
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
 
-    pub type ParseError = peg::error::ParseError<peg::str::LineCol>;
+    fn part1(data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(data)?.into())
+    }
 
-    pub fn parse(s: &str) -> Result<Data, ParseError> {
-        parser::parse(s)
+    fn part2(data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(data)?.into())
     }
+}
 
-    peg::parser! {
-        grammar parser() for str {
-            pub rule parse() -> Data
-                = ss:scenario() ++ eol()
-                {
-                    ss
-                }
-
-            rule scenario() -> Scenario
-                = sps:pattern() ++ _ _ "|" _ ovs:pattern() ++ _
-                {
-                    Scenario::new(sps, ovs)
-                }
-
-            rule pattern() -> Pattern
-                = ss:segment()+
-                {
-                    ss.into_iter().collect::<Pattern>()
-                }
-
-            rule segment() -> Segment
-                = "a" { Segment::A }
-                / "b" { Segment::B }
-                / "c" { Segment::C }
-                / "d" { Segment::D }
-                / "e" { Segment::E }
-                / "f" { Segment::F }
-                / "g" { Segment::G }
-
-            rule _()
-                = [' ']+
-
-            rule eol()
-                = "\n"
-                / "\r"
-                / "\r" "\n"
-        }
+fn parse(data: &str) -> Result<Data, AocError> {
+    parsers::lines(data, |l| {
+        let (signals, outputs) = l
+            .split_once('|')
+            .ok_or_else(|| AocError::invalid_input("expected signal patterns and output values"))?;
+
+        Ok(Scenario::new(patterns(signals)?, patterns(outputs)?))
+    })
+}
+
+// Whitespace separated patterns of segments (e.g. "cf acdeg").
+fn patterns(s: &str) -> Result<Vec<Pattern>, AocError> {
+    let rv = s
+        .split_whitespace()
+        .map(|p| p.chars().map(Segment::parse).collect())
+        .collect::<Result<Vec<Pattern>, _>>()?;
+
+    if rv.is_empty() {
+        return Err(AocError::invalid_input("expected a pattern"));
     }
+
+    Ok(rv)
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
@@ -73,6 +56,23 @@ pub enum Segment {
     G,
 }
 
+impl Segment {
+    fn parse(ch: char) -> Result<Self, AocError> {
+        match ch {
+            'a' => Ok(Self::A),
+            'b' => Ok(Self::B),
+            'c' => Ok(Self::C),
+            'd' => Ok(Self::D),
+            'e' => Ok(Self::E),
+            'f' => Ok(Self::F),
+            'g' => Ok(Self::G),
+            _ => Err(AocError::invalid_input(
+                format!("unknown segment {}", ch).as_str(),
+            )),
+        }
+    }
+}
+
 pub type Pattern = std::collections::HashSet<Segment>;
 
 fn new_full_pattern() -> Pattern {
@@ -259,50 +259,46 @@ impl Scenario {
         // Apply the 1, 7, 4, 3 pattern deductions to the encoder.
         self.signal_patterns.iter().for_each(|v| {
             if v.len() == 2 {
-                vec![Segment::C, Segment::F].iter().for_each(|s| {
+                [Segment::C, Segment::F].iter().for_each(|s| {
                     let entry = encoder.get_mut(s).unwrap();
                     *entry = entry.intersection(v).copied().collect::<Pattern>();
                 });
-                vec![Segment::A, Segment::B, Segment::D, Segment::E, Segment::G]
+                [Segment::A, Segment::B, Segment::D, Segment::E, Segment::G]
                     .iter()
                     .for_each(|s| {
                         let entry = encoder.get_mut(s).unwrap();
                         *entry = entry.difference(v).copied().collect::<Pattern>();
                     });
             } else if v.len() == 3 {
-                vec![Segment::A, Segment::C, Segment::F]
-                    .iter()
-                    .for_each(|s| {
-                        let entry = encoder.get_mut(s).unwrap();
-                        *entry = entry.intersection(v).copied().collect::<Pattern>();
-                    });
-                vec![Segment::B, Segment::D, Segment::E, Segment::G]
+                [Segment::A, Segment::C, Segment::F].iter().for_each(|s| {
+                    let entry = encoder.get_mut(s).unwrap();
+                    *entry = entry.intersection(v).copied().collect::<Pattern>();
+                });
+                [Segment::B, Segment::D, Segment::E, Segment::G]
                     .iter()
                     .for_each(|s| {
                         let entry = encoder.get_mut(s).unwrap();
                         *entry = entry.difference(v).copied().collect::<Pattern>();
                     });
             } else if v.len() == 4 {
-                vec![Segment::B, Segment::C, Segment::D, Segment::F]
+                [Segment::B, Segment::C, Segment::D, Segment::F]
                     .iter()
                     .for_each(|s| {
                         let entry = encoder.get_mut(s).unwrap();
                         *entry = entry.intersection(v).copied().collect::<Pattern>();
                     });
-                vec![Segment::A, Segment::E, Segment::G]
-                    .iter()
-                    .for_each(|s| {
-                        let entry = encoder.get_mut(s).unwrap();
-                        *entry = entry.difference(v).copied().collect::<Pattern>();
-                    });
+                [Segment::A, Segment::E, Segment::G].iter().for_each(|s| {
+                    let entry = encoder.get_mut(s).unwrap();
+                    *entry = entry.difference(v).copied().collect::<Pattern>();
+                });
             } else if v.len() == 5 && *v == three_pattern {
-                vec![Segment::A, Segment::C, Segment::D, Segment::F, Segment::G]
+                [Segment::A, Segment::C, Segment::D, Segment::F, Segment::G]
                     .iter()
                     .for_each(|s| {
                         let entry = encoder.get_mut(s).unwrap();
                         *entry = entry.intersection(v).copied().collect::<Pattern>();
                     });
-                vec![Segment::B, Segment::E].iter().for_each(|s| {
+                [Segment::B, Segment::E].iter().for_each(|s| {
                     let entry = encoder.get_mut(s).unwrap();
                     *entry = entry.difference(v).copied().collect::<Pattern>();
                 });
@@ -311,30 +307,28 @@ impl Scenario {
 
         // Identify the 2 pattern.
         let two_pattern_superset: Pattern =
-            vec![Segment::A, Segment::C, Segment::D, Segment::E, Segment::G]
+            [Segment::A, Segment::C, Segment::D, Segment::E, Segment::G]
                 .iter()
-                .map(|v| encoder.get(v).unwrap())
-                .flatten()
+                .flat_map(|v| encoder.get(v).unwrap())
                 .copied()
                 .collect();
 
-        let two_pattern: Pattern;
-        if possible_two_patterns[0].is_subset(&two_pattern_superset) {
-            two_pattern = possible_two_patterns[0].clone();
+        let two_pattern: Pattern = if possible_two_patterns[0].is_subset(&two_pattern_superset) {
+            possible_two_patterns[0].clone()
         } else {
-            two_pattern = possible_two_patterns[1].clone();
-        }
+            possible_two_patterns[1].clone()
+        };
 
         // Apply the 2 pattern deduction.
         self.signal_patterns.iter().for_each(|v| {
             if v.len() == 5 && *v == two_pattern {
-                vec![Segment::A, Segment::C, Segment::D, Segment::E, Segment::G]
+                [Segment::A, Segment::C, Segment::D, Segment::E, Segment::G]
                     .iter()
                     .for_each(|s| {
                         let entry = encoder.get_mut(s).unwrap();
                         *entry = entry.intersection(v).copied().collect::<Pattern>();
                     });
-                vec![Segment::B, Segment::F].iter().for_each(|s| {
+                [Segment::B, Segment::F].iter().for_each(|s| {
                     let entry = encoder.get_mut(s).unwrap();
                     *entry = entry.difference(v).copied().collect::<Pattern>();
                 });
@@ -352,13 +346,13 @@ impl Scenario {
 
         // Generate (and return) a closure that uses the decoder to map from an encoded segment to
         // the decoded segment.
-        Box::new(move |from: Segment| decoder.get(&from).unwrap().clone())
+        Box::new(move |from: Segment| *decoder.get(&from).unwrap())
     }
 }
 
 pub type Data = Vec<Scenario>;
 
-fn part1(data: &Data) -> Result<usize, Box<dyn std::error::Error>> {
+fn part1(data: &Data) -> Result<usize, AocError> {
     let count = data
         .iter()
         .map(|s| {
@@ -375,7 +369,7 @@ fn part1(data: &Data) -> Result<usize, Box<dyn std::error::Error>> {
 
 #[test]
 fn test_part1_sample() -> Result<(), Box<dyn std::error::Error>> {
-    let input = aoc::read_file("input/day08.test.txt")?;
+    let input = crate::input::InputSource::sample(8).unwrap().read()?;
     let data = parse(&input)?;
     let result = part1(&data);
     assert!(result.is_ok());
@@ -386,7 +380,7 @@ fn test_part1_sample() -> Result<(), Box<dyn std::error::Error>> {
 
 #[test]
 fn test_part1_full() -> Result<(), Box<dyn std::error::Error>> {
-    let input = aoc::read_file("input/day08.txt")?;
+    let input = crate::input::InputSource::full(8).read()?;
     let data = parse(&input)?;
     let result = part1(&data);
     assert!(result.is_ok());
@@ -395,7 +389,7 @@ fn test_part1_full() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn part2(data: &Data) -> Result<usize, Box<dyn std::error::Error>> {
+fn part2(data: &Data) -> Result<usize, AocError> {
     let count = data
         .iter()
         .map(|s| {
@@ -414,7 +408,7 @@ fn part2(data: &Data) -> Result<usize, Box<dyn std::error::Error>> {
 
 #[test]
 fn test_part2_sample() -> Result<(), Box<dyn std::error::Error>> {
-    let input = aoc::read_file("input/day08.test.txt")?;
+    let input = crate::input::InputSource::sample(8).unwrap().read()?;
     let data = parse(&input)?;
     let result = part2(&data);
     assert!(result.is_ok());
@@ -425,7 +419,7 @@ fn test_part2_sample() -> Result<(), Box<dyn std::error::Error>> {
 
 #[test]
 fn test_part2_full() -> Result<(), Box<dyn std::error::Error>> {
-    let input = aoc::read_file("input/day08.txt")?;
+    let input = crate::input::InputSource::full(8).read()?;
     let data = parse(&input)?;
     let result = part2(&data);
     assert!(result.is_ok());
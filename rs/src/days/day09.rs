@@ -0,0 +1,101 @@
+use crate::grid2d::Neighborhood;
+use crate::render::Image;
+use crate::{Answer, AocError, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: usize = 9;
+
+    type Input = Grid2D;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(data)?.into())
+    }
+
+    fn part2(data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(data)?.into())
+    }
+
+    // The height map, with the ridges of height 9 between the basins at the top level.
+    fn render(data: &Self::Input) -> Option<Image> {
+        Some(Image::new(data.map(|v| v as u16), 9))
+    }
+}
+
+type Grid2D = crate::grid2d::Grid2D<usize>;
+type Grid2DPoint = crate::grid2d::Grid2DPoint<usize>;
+
+fn parse(data: &str) -> Result<Grid2D, AocError> {
+    crate::parsers::digit_grid(data)
+}
+
+fn part1(data: &Grid2D) -> Result<usize, AocError> {
+    let n = get_low_points(data).iter().map(|p| p.value + 1).sum();
+
+    Ok(n)
+}
+
+fn part2(data: &Grid2D) -> Result<usize, AocError> {
+    // Every point that is not a boundary point (i.e. boundary points have value 9) belongs to
+    // exactly one basin
+    let basins = crate::search::label_components(data, |p| p.value < 9, Neighborhood::Four);
+    let mut ns: Vec<_> = basins.iter().map(|b| b.size).collect();
+
+    ns.sort();
+
+    let n = ns.iter().rev().take(3).product();
+    Ok(n)
+}
+
+fn get_low_points(data: &Grid2D) -> Vec<Grid2DPoint> {
+    data.iter()
+        .filter(|p| {
+            data.neighbors4(p.y, p.x)
+                // Check if all adjacent point values are greater than the current point
+                .all(|pp| pp.value > p.value)
+        })
+        .collect()
+}
+
+#[test]
+fn test_simulate_sample() {
+    let input = crate::input::InputSource::sample(9).unwrap().read();
+    assert!(input.is_ok());
+    let input = input.unwrap();
+
+    let data = parse(&input);
+    assert!(data.is_ok());
+    let data = data.unwrap();
+
+    let result = part1(&data);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 15);
+
+    let result = part2(&data);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 1134);
+}
+
+#[test]
+fn test_simulate_full() {
+    let input = crate::input::InputSource::full(9).read();
+    assert!(input.is_ok());
+    let input = input.unwrap();
+
+    let data = parse(&input);
+    assert!(data.is_ok());
+    let data = data.unwrap();
+
+    let result = part1(&data);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 494);
+
+    let result = part2(&data);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 1048128);
+}
@@ -1,12 +1,23 @@
-use aoc;
+use crate::{Answer, AocError, Solution};
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input = aoc::read_file("input/day10.txt")?;
-    let data = parse(&input);
-    println!("part1: {:?}", part1(&data));
-    println!("part2: {:?}", part2(&data));
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: usize = 10;
+
+    type Input = Vec<TunnelMap>;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse(input))
+    }
+
+    fn part1(data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(data)?.into())
+    }
+
+    fn part2(data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(data)?.into())
+    }
 }
 
 fn parse(data: &str) -> Vec<TunnelMap> {
@@ -32,10 +43,10 @@ fn parse(data: &str) -> Vec<TunnelMap> {
             }
 
             match state.pop() {
-                None => return TunnelMap::Complete,
+                None => TunnelMap::Complete,
                 Some(cc) => {
                     state.push(cc);
-                    return TunnelMap::Incomplete(state.into_iter().rev().collect());
+                    TunnelMap::Incomplete(state.into_iter().rev().collect())
                 }
             }
         })
@@ -115,7 +126,7 @@ fn map_close_char_to_completion_points(c: char) -> usize {
     }
 }
 
-fn part1(data: &[TunnelMap]) -> Result<usize, Box<dyn std::error::Error>> {
+fn part1(data: &[TunnelMap]) -> Result<usize, AocError> {
     let rv = data
         .iter()
         .map(|v| match v {
@@ -127,13 +138,13 @@ fn part1(data: &[TunnelMap]) -> Result<usize, Box<dyn std::error::Error>> {
     Ok(rv)
 }
 
-fn part2(data: &[TunnelMap]) -> Result<usize, Box<dyn std::error::Error>> {
+fn part2(data: &[TunnelMap]) -> Result<usize, AocError> {
     let mut rv: Vec<_> = data
         .iter()
         .filter_map(|v| match v {
             TunnelMap::Incomplete(s) => Some(
                 s.chars()
-                    .map(|c| map_close_char_to_completion_points(c))
+                    .map(map_close_char_to_completion_points)
                     .fold(0, |acc, v| acc * 5 + v),
             ),
             _ => None,
@@ -144,12 +155,23 @@ fn part2(data: &[TunnelMap]) -> Result<usize, Box<dyn std::error::Error>> {
     // We were told to assume there is always an odd number so a simple "middle value" is the
     // median.
     let mi = rv.len() / 2;
-    Ok(rv[mi])
+    rv.get(mi)
+        .copied()
+        .ok_or_else(|| AocError::unsolvable("no line is incomplete"))
+}
+
+#[test]
+fn test_part2_without_incomplete_lines() {
+    assert!(matches!(part2(&[]), Err(AocError::Unsolvable(_))));
+    assert!(matches!(
+        part2(&parse("[]\n(]")),
+        Err(AocError::Unsolvable(_))
+    ));
 }
 
 #[test]
 fn test_simulate_sample() {
-    let input = aoc::read_file("input/day10.test.txt");
+    let input = crate::input::InputSource::sample(10).unwrap().read();
     assert!(input.is_ok());
     let input = input.unwrap();
 
@@ -167,7 +189,7 @@ fn test_simulate_sample() {
 
 #[test]
 fn test_simulate_full() {
-    let input = aoc::read_file("input/day10.txt");
+    let input = crate::input::InputSource::full(10).read();
     assert!(input.is_ok());
     let input = input.unwrap();
 
@@ -0,0 +1,105 @@
+use crate::automaton::Automaton;
+use crate::grid2d::Neighborhood;
+use crate::render::Image;
+use crate::{Answer, AocError, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: usize = 11;
+
+    type Input = Grid2D;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(data)?.into())
+    }
+
+    fn part2(data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(data)?.into())
+    }
+
+    // The energy levels of the octopuses after the steps of part 1.
+    fn render(data: &Self::Input) -> Option<Image> {
+        let mut octopuses = Automaton::new(data.clone(), Neighborhood::Eight);
+        (0..100).for_each(|_| {
+            octopuses.step(flash);
+        });
+
+        Some(Image::new(octopuses.grid().map(|v| v as u16), 9))
+    }
+}
+
+type Grid2D = crate::grid2d::Grid2D<usize>;
+
+fn parse(data: &str) -> Result<Grid2D, AocError> {
+    crate::parsers::digit_grid(data)
+}
+
+fn part1(data: &Grid2D) -> Result<usize, AocError> {
+    let mut octopuses = Automaton::new(data.clone(), Neighborhood::Eight);
+    let total_flashes = (0..100).map(|_| octopuses.step(flash)).sum();
+
+    Ok(total_flashes)
+}
+
+fn part2(data: &Grid2D) -> Result<usize, AocError> {
+    let mut octopuses = Automaton::new(data.clone(), Neighborhood::Eight);
+    let expected_step_flashes = data.width() * data.height();
+
+    octopuses
+        .try_run_until(flash, |flashes| *flashes == expected_step_flashes)
+        .ok_or_else(|| AocError::unsolvable("the octopuses never all flash in the same step"))
+}
+
+// The phases of one step, returning the number of octopuses that flashed: every octopus gains
+// energy, those above 9 flash (which raises the energy of the octopuses around them, which may
+// then flash as well) and the ones that flashed are reset.
+fn flash(octopuses: &mut Automaton<usize>) -> usize {
+    octopuses.update(|v| v + 1);
+    let flashes = octopuses.cascade(|v| v > 9, |v| v + 1);
+    octopuses.update(|v| if v > 9 { 0 } else { v });
+
+    flashes
+}
+
+#[test]
+fn test_simulate_sample() {
+    let input = crate::input::InputSource::sample(11).unwrap().read();
+    assert!(input.is_ok());
+    let input = input.unwrap();
+
+    let data = parse(&input);
+    assert!(data.is_ok());
+    let data = data.unwrap();
+
+    let result = part1(&data);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 1656);
+
+    let result = part2(&data);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 195);
+}
+
+#[test]
+fn test_simulate_full() {
+    let input = crate::input::InputSource::full(11).read();
+    assert!(input.is_ok());
+    let input = input.unwrap();
+
+    let data = parse(&input);
+    assert!(data.is_ok());
+    let data = data.unwrap();
+
+    let result = part1(&data);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 1793);
+
+    let result = part2(&data);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 247);
+}
@@ -0,0 +1,443 @@
+use crate::graph::{Graph, NodeId};
+use crate::parsers;
+use crate::{Answer, AocError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: usize = 12;
+
+    type Input = Caves;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part1(data)?.into())
+    }
+
+    fn part2(data: &Self::Input) -> Result<Answer, AocError> {
+        Ok(part2(data)?.into())
+    }
+
+    fn dot(data: &Self::Input) -> Option<String> {
+        Some(data.to_dot(|n| n.to_string(), |_| None))
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum Node {
+    Start,
+    End,
+    Big(String),
+    Small(String),
+}
+
+impl std::fmt::Display for Node {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Start => write!(fmt, "start"),
+            Self::End => write!(fmt, "end"),
+            Self::Big(name) | Self::Small(name) => write!(fmt, "{}", name),
+        }
+    }
+}
+
+impl Node {
+    fn parse(s: &str) -> Result<Self, AocError> {
+        match s {
+            "start" => Ok(Self::Start),
+            "end" => Ok(Self::End),
+            _ if !s.is_empty() && s.chars().all(|ch| ch.is_ascii_lowercase()) => {
+                Ok(Self::Small(s.to_string()))
+            }
+            _ if !s.is_empty() && s.chars().all(|ch| ch.is_ascii_uppercase()) => {
+                Ok(Self::Big(s.to_string()))
+            }
+            _ => Err(AocError::invalid_input(
+                format!("invalid cave name {}", s).as_str(),
+            )),
+        }
+    }
+}
+
+// The caves are joined by undirected passages.
+pub type Caves = Graph<Node, ()>;
+
+fn parse(data: &str) -> Result<Caves, AocError> {
+    let passages = parsers::lines(data, |l| {
+        let (n1, n2) = l
+            .split_once('-')
+            .ok_or_else(|| AocError::invalid_input("expected two caves"))?;
+
+        Ok((Node::parse(n1)?, Node::parse(n2)?))
+    })?;
+
+    let mut caves = Caves::undirected();
+    passages.into_iter().for_each(|(n1, n2)| {
+        caves.add_edge(n1, n2, ());
+    });
+
+    Ok(caves)
+}
+
+fn part1(data: &Caves) -> Result<usize, AocError> {
+    count_paths(data, false)
+}
+
+fn part2(data: &Caves) -> Result<usize, AocError> {
+    count_paths(data, true)
+}
+
+// The rules for visiting small caves on the paths listed by paths (given the path so far and the
+// small cave it would go on to).
+
+// Every small cave can be visited once.
+pub fn visit_small_once(_: &Caves, path: &[NodeId], id: NodeId) -> bool {
+    !path.contains(&id)
+}
+
+// A single small cave can be visited twice and every other small cave once.
+pub fn visit_one_small_twice(caves: &Caves, path: &[NodeId], id: NodeId) -> bool {
+    // can always traverse a small node at least once
+    if !path.contains(&id) {
+        return true;
+    }
+
+    // if any small node has been visited more than once then we cannot traverse to this node
+    let mut visited = HashSet::new();
+    path.iter()
+        .filter(|id| matches!(caves.node(**id), Node::Small(_)))
+        .all(|id| visited.insert(*id))
+}
+
+// The paths from the start to the end cave where small caves are visited according to a rule (big
+// caves can always be visited again), found lazily as the paths are iterated.
+pub fn paths(
+    data: &Caves,
+    can_traverse_fn: fn(&Caves, &[NodeId], NodeId) -> bool,
+) -> Result<Paths<'_>, AocError> {
+    let start = start_cave(data)?;
+    check_finite_paths(data)?;
+
+    Ok(Paths {
+        caves: data,
+        can_traverse_fn,
+        partial_paths: vec![vec![start]],
+    })
+}
+
+// Paths is a depth first walk of the paths through the caves.
+pub struct Paths<'a> {
+    caves: &'a Caves,
+    can_traverse_fn: fn(&Caves, &[NodeId], NodeId) -> bool,
+    partial_paths: Vec<Vec<NodeId>>,
+}
+
+impl<'a> Iterator for Paths<'a> {
+    type Item = CavePath<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let data = self.caves;
+
+        // get a partial path
+        while let Some(curr_path) = self.partial_paths.pop() {
+            // get the last node of the selected path
+            let curr_node = *curr_path.last().unwrap();
+
+            // reaching the end node means completion
+            if *data.node(curr_node) == Node::End {
+                return Some(CavePath {
+                    caves: data,
+                    nodes: curr_path,
+                });
+            }
+
+            // foreach possible next node (based on the last node)
+            let next_nodes: Vec<_> = data
+                .neighbors(curr_node)
+                .filter(|next_node| match data.node(*next_node) {
+                    // we do not allow going back to start
+                    Node::Start => false,
+                    // we can always traverse to the end or back to a big node
+                    Node::End | Node::Big(_) => true,
+                    // check if we can traverse back to a small node
+                    Node::Small(_) => (self.can_traverse_fn)(data, &curr_path, *next_node),
+                })
+                .collect();
+
+            // push in reverse so that the paths are found in the order of the passages
+            next_nodes.into_iter().rev().for_each(|next_node| {
+                let mut new_path = curr_path.clone();
+                new_path.push(next_node);
+
+                self.partial_paths.push(new_path);
+            });
+        }
+
+        None
+    }
+}
+
+// CavePath is a path from the start to the end cave (displayed as "start,A,b,end").
+#[derive(Debug, Clone)]
+pub struct CavePath<'a> {
+    caves: &'a Caves,
+    nodes: Vec<NodeId>,
+}
+
+impl<'a> CavePath<'a> {
+    pub fn nodes(&self) -> impl Iterator<Item = &'a Node> + '_ {
+        self.nodes.iter().map(|id| self.caves.node(*id))
+    }
+
+    // The number of caves on the path (counting each visit).
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn passes_through(&self, node: &Node) -> bool {
+        self.nodes().any(|n| n == node)
+    }
+}
+
+impl<'a> std::fmt::Display for CavePath<'a> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.nodes().enumerate().try_for_each(|(i, n)| {
+            if i > 0 {
+                write!(fmt, ",")?;
+            }
+
+            write!(fmt, "{}", n)
+        })
+    }
+}
+
+// The number of paths from the start to the end cave where small caves are visited once (except
+// for a single small cave that may be visited twice if small_twice is set).
+//
+// Rather than listing the paths, the number of ways on from a cave is memoised: it only depends on
+// the cave, the small caves that have been visited (as a bitmask) and whether a small cave has
+// been visited twice yet.
+pub fn count_paths(data: &Caves, small_twice: bool) -> Result<usize, AocError> {
+    let start = start_cave(data)?;
+    check_finite_paths(data)?;
+
+    // the bit of each small cave in the bitmask of visited caves
+    let mut bits = vec![None; data.len()];
+    let small_caves = data
+        .nodes()
+        .filter(|(_, n)| matches!(n, Node::Small(_)))
+        .map(|(id, _)| id);
+    for (bit, id) in small_caves.enumerate() {
+        if bit >= u64::BITS as usize {
+            return Err(AocError::invalid_input(
+                format!("more than {} small caves", u64::BITS).as_str(),
+            ));
+        }
+
+        bits[id.index()] = Some(bit);
+    }
+
+    let mut counter = PathCounter {
+        caves: data,
+        bits,
+        memo: HashMap::new(),
+    };
+
+    Ok(counter.count(start, 0, !small_twice))
+}
+
+struct PathCounter<'a> {
+    caves: &'a Caves,
+    bits: Vec<Option<usize>>,
+    memo: HashMap<(NodeId, u64, bool), usize>,
+}
+
+impl<'a> PathCounter<'a> {
+    // The number of ways from a cave to the end cave.
+    fn count(&mut self, id: NodeId, visited: u64, twice_used: bool) -> usize {
+        if let Some(rv) = self.memo.get(&(id, visited, twice_used)) {
+            return *rv;
+        }
+
+        let caves = self.caves;
+        let rv = caves
+            .neighbors(id)
+            .map(|next| match caves.node(next) {
+                // we do not allow going back to start
+                Node::Start => 0,
+                // reaching the end node means completion
+                Node::End => 1,
+                Node::Big(_) => self.count(next, visited, twice_used),
+                Node::Small(_) => {
+                    let bit = 1 << self.bits[next.index()].unwrap();
+
+                    if visited & bit == 0 {
+                        self.count(next, visited | bit, twice_used)
+                    } else if !twice_used {
+                        self.count(next, visited, true)
+                    } else {
+                        0
+                    }
+                }
+            })
+            .sum();
+
+        self.memo.insert((id, visited, twice_used), rv);
+
+        rv
+    }
+}
+
+fn start_cave(data: &Caves) -> Result<NodeId, AocError> {
+    data.id(&Node::Start)
+        .ok_or_else(|| AocError::invalid_input("there is no start cave"))
+}
+
+// A passage between two big caves could be followed back and forth forever, so there would be
+// endless paths.
+fn check_finite_paths(data: &Caves) -> Result<(), AocError> {
+    match data
+        .nodes()
+        .filter(|(_, n)| matches!(n, Node::Big(_)))
+        .flat_map(|(id, n)| data.neighbors(id).map(move |next| (n, next)))
+        .map(|(n, next)| (n, data.node(next)))
+        .find(|(_, next)| matches!(next, Node::Big(_)))
+    {
+        Some((a, b)) => Err(AocError::unsolvable(
+            format!("the big caves {} and {} lead to endless paths", a, b).as_str(),
+        )),
+        None => Ok(()),
+    }
+}
+
+#[test]
+fn test_simulate_sample() {
+    let input = crate::input::InputSource::sample(12).unwrap().read();
+    assert!(input.is_ok());
+    let input = input.unwrap();
+
+    let data = parse(&input);
+    assert!(data.is_ok());
+    let data = data.unwrap();
+
+    let result = part1(&data);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 10);
+
+    let result = part2(&data);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 36);
+}
+
+#[test]
+fn test_count_paths() -> Result<(), AocError> {
+    let input = crate::input::InputSource::sample(12).unwrap().read()?;
+    let data = parse(&input)?;
+
+    // counting and listing the paths agree
+    assert_eq!(paths(&data, visit_small_once)?.count(), 10);
+    assert_eq!(count_paths(&data, false)?, 10);
+    assert_eq!(paths(&data, visit_one_small_twice)?.count(), 36);
+    assert_eq!(count_paths(&data, true)?, 36);
+
+    let data = parse("start-A\nA-B\nB-end")?;
+    assert!(matches!(
+        count_paths(&data, false),
+        Err(AocError::Unsolvable(_))
+    ));
+    assert!(matches!(
+        paths(&data, visit_small_once),
+        Err(AocError::Unsolvable(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_paths() -> Result<(), AocError> {
+    let input = crate::input::InputSource::sample(12).unwrap().read()?;
+    let data = parse(&input)?;
+
+    let mut rendered: Vec<_> = paths(&data, visit_small_once)?
+        .map(|p| p.to_string())
+        .collect();
+    rendered.sort();
+    assert_eq!(
+        rendered,
+        vec![
+            "start,A,b,A,c,A,end",
+            "start,A,b,A,end",
+            "start,A,b,end",
+            "start,A,c,A,b,A,end",
+            "start,A,c,A,b,end",
+            "start,A,c,A,end",
+            "start,A,end",
+            "start,b,A,c,A,end",
+            "start,b,A,end",
+            "start,b,end",
+        ]
+    );
+
+    // the paths are found lazily in the order of the passages
+    let first = paths(&data, visit_small_once)?.next().unwrap();
+    assert_eq!(first.to_string(), "start,A,c,A,b,A,end");
+    assert_eq!(first.len(), 7);
+
+    let c = Node::Small("c".to_string());
+    assert_eq!(
+        paths(&data, visit_small_once)?
+            .filter(|p| p.passes_through(&c))
+            .count(),
+        5
+    );
+    assert_eq!(
+        paths(&data, visit_one_small_twice)?
+            .filter(|p| p.len() == 3)
+            .count(),
+        2
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_simulate_full() {
+    let input = crate::input::InputSource::full(12).read();
+    assert!(input.is_ok());
+    let input = input.unwrap();
+
+    let data = parse(&input);
+    assert!(data.is_ok());
+    let data = data.unwrap();
+
+    let result = part1(&data);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 4912);
+
+    let result = part2(&data);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 150004);
+}
+
+#[test]
+fn test_dot() -> Result<(), AocError> {
+    let data = parse("start-A\nA-end")?;
+
+    assert_eq!(
+        Day12::dot(&data),
+        Some(
+            "graph {\n    n0 [label=\"start\"];\n    n1 [label=\"A\"];\n    n2 [label=\"end\"];\n    n0 -- n1;\n    n1 -- n2;\n}\n"
+                .to_string()
+        )
+    );
+
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use crate::grid2d::Grid2D;
+use crate::pos::Pos;
+use crate::render::Image;
+use crate::{parsers, Answer, AocError, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: usize = 13;
+
+    type Input = (Points, Folds);
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError> {
+        let (points, folds) = input;
+
+        Ok(part1(points, folds)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError> {
+        let (points, folds) = input;
+
+        Ok(part2(points, folds)?.into())
+    }
+
+    // The paper with the dots that are left after every fold.
+    fn render(input: &Self::Input) -> Option<Image> {
+        let (points, folds) = input;
+        let folded_points = crop_to_dots(&fold_all(points, folds));
+
+        Some(Image::new(folded_points.map(u16::from), 1))
+    }
+}
+
+// The points are a grid of the paper where the dots are true.
+pub type Points = Grid2D<bool>;
+
+// Crop the paper to the dots (but keep leading empty rows and columns).
+fn crop_to_dots(points: &Points) -> Points {
+    let (width, height) = points
+        .iter()
+        .filter(|p| p.value)
+        .fold((0, 0), |(w, h), p| (w.max(p.x + 1), h.max(p.y + 1)));
+
+    points
+        .crop(Pos::ORIGIN, width, height)
+        .unwrap_or_else(|| points.clone())
+}
+
+fn render_points(points: &Points) -> String {
+    crop_to_dots(points)
+        .display(|v| if v { '#' } else { '.' })
+        .to_string()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+#[derive(Debug)]
+pub struct Fold(Axis, usize);
+
+pub type Folds = Vec<Fold>;
+
+fn parse(data: &str) -> Result<(Points, Folds), AocError> {
+    match parsers::sections(data)[..] {
+        [points, folds] => {
+            let points = points.parse(parsers::points)?;
+            if points.iter().any(|p| p.to_yx().is_none()) {
+                return Err(AocError::invalid_input("points must not be negative"));
+            }
+
+            let folds = folds.parse(parse_folds)?;
+
+            // the first fold along each axis is through the middle of the paper, which must also
+            // be large enough for the points
+            let size = |axis: Axis, coord: fn(&Pos) -> isize| {
+                let folded = folds
+                    .iter()
+                    .find(|f| f.0 == axis)
+                    .map_or(0, |f| 2 * f.1 + 1);
+                let dotted = points.iter().map(|p| coord(p) + 1).max().unwrap_or(0);
+
+                folded.max(dotted as usize)
+            };
+            let mut paper = Points::filled(size(Axis::X, |p| p.x), size(Axis::Y, |p| p.y), false);
+            points.into_iter().for_each(|p| {
+                paper.set(p, true);
+            });
+
+            Ok((paper, folds))
+        }
+        _ => Err(AocError::invalid_input(
+            "expected points and folds separated by a blank line",
+        )),
+    }
+}
+
+fn parse_folds(data: &str) -> Result<Folds, AocError> {
+    data.lines()
+        .map(|l| {
+            let fold = l
+                .strip_prefix("fold along ")
+                .and_then(|f| f.split_once('='));
+            match fold {
+                Some(("x", v)) => Ok(Fold(Axis::X, parsers::unsigned(v)?)),
+                Some(("y", v)) => Ok(Fold(Axis::Y, parsers::unsigned(v)?)),
+                _ => Err(AocError::invalid_input(
+                    format!("invalid fold '{}'", l).as_str(),
+                )),
+            }
+        })
+        .collect()
+}
+
+fn part1(points: &Points, folds: &Folds) -> Result<usize, AocError> {
+    let fold = folds
+        .first()
+        .ok_or_else(|| AocError::invalid_input("there are no folds"))?;
+    let folded_points = do_fold(points, fold);
+    Ok(folded_points.iter().filter(|p| p.value).count())
+}
+
+fn part2(points: &Points, folds: &Folds) -> Result<String, AocError> {
+    let folded_points = fold_all(points, folds);
+
+    let drawing = render_points(&folded_points);
+
+    // the folded points should draw letters but fall back to the drawing itself if not
+    Ok(crate::ocr::read_letters(&drawing).unwrap_or(drawing))
+}
+
+fn fold_all(points: &Points, folds: &Folds) -> Points {
+    folds.iter().fold(points.clone(), |acc, f| do_fold(&acc, f))
+}
+
+fn do_fold(points: &Points, fold: &Fold) -> Points {
+    match *fold {
+        Fold(Axis::X, value) => points.fold_x(value, |a, b| a || b),
+        Fold(Axis::Y, value) => points.fold_y(value, |a, b| a || b),
+    }
+}
+
+#[test]
+fn test_simulate_sample() {
+    let input = crate::input::InputSource::sample(13).unwrap().read();
+    assert!(input.is_ok());
+    let input = input.unwrap();
+
+    let data = parse(&input);
+    assert!(data.is_ok());
+    let (points, folds) = data.unwrap();
+
+    let result = part1(&points, &folds);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 17);
+
+    let result = part2(&points, &folds);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), "#####\n#...#\n#...#\n#...#\n#####");
+}
+
+#[test]
+fn test_simulate_full() {
+    let input = crate::input::InputSource::full(13).read();
+    assert!(input.is_ok());
+    let input = input.unwrap();
+
+    let data = parse(&input);
+    assert!(data.is_ok());
+    let (points, folds) = data.unwrap();
+
+    let result = part1(&points, &folds);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), 807);
+
+    let result = part2(&points, &folds);
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap(), "LGHEGUEJ");
+}
+
+#[test]
+fn test_fold_beyond_points() -> Result<(), AocError> {
+    let (points, folds) = parse("0,0\n1,1\n\nfold along y=5")?;
+    assert_eq!((points.width(), points.height()), (2, 11));
+    assert_eq!(part1(&points, &folds)?, 2);
+    assert!(part1(&points, &vec![]).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+
+use crate::bench::Stages;
+use crate::input::InputSource;
+use crate::render::Image;
+use crate::{Answer, AocError, Part, Solution};
+
+pub type SolveFn = fn(&str, Part) -> Result<Answer, AocError>;
+pub type BenchFn = fn(&str, usize) -> Result<Stages, AocError>;
+pub type RenderFn = fn(&str) -> Result<Option<Image>, AocError>;
+pub type DotFn = fn(&str) -> Result<Option<String>, AocError>;
+
+// Entry is a single registered day and the type-erased stages of its Solution.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub day: usize,
+    pub solve: SolveFn,
+    pub bench: BenchFn,
+    pub render: RenderFn,
+    pub dot: DotFn,
+}
+
+impl Entry {
+    const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            solve: crate::solve::<S>,
+            bench: crate::bench::run::<S>,
+            render: crate::render::run::<S>,
+            dot: crate::graph::dot::<S>,
+        }
+    }
+
+    // The source of a named input of the entry (see InputSource::named).
+    pub fn input(&self, name: &str) -> InputSource {
+        InputSource::named(self.day, name)
+    }
+}
+
+const REGISTRY: &[Entry] = &[
+    Entry::of::<day01::Day01>(),
+    Entry::of::<day02::Day02>(),
+    Entry::of::<day03::Day03>(),
+    Entry::of::<day04::Day04>(),
+    Entry::of::<day05::Day05>(),
+    Entry::of::<day06::Day06>(),
+    Entry::of::<day07::Day07>(),
+    Entry::of::<day08::Day08>(),
+    Entry::of::<day09::Day09>(),
+    Entry::of::<day10::Day10>(),
+    Entry::of::<day11::Day11>(),
+    Entry::of::<day12::Day12>(),
+    Entry::of::<day13::Day13>(),
+];
+
+// All registered entries ordered by day.
+pub fn registry() -> &'static [Entry] {
+    REGISTRY
+}
+
+// The registered entry for a day.
+pub fn find(day: usize) -> Option<Entry> {
+    REGISTRY.iter().find(|e| e.day == day).copied()
+}
+
+#[test]
+fn test_registry_is_ordered_and_unique() {
+    let days: Vec<_> = registry().iter().map(|e| e.day).collect();
+
+    let mut sorted = days.clone();
+    sorted.sort();
+    sorted.dedup();
+
+    assert_eq!(days, sorted);
+    assert!(find(13).is_some());
+    assert!(find(14).is_none());
+}
+
+#[test]
+fn test_solve_sample() -> Result<(), Box<dyn std::error::Error>> {
+    let input = InputSource::sample(5).unwrap().read()?;
+    let entry = find(5).unwrap();
+
+    assert_eq!((entry.solve)(&input, Part::One)?, Answer::Number(5));
+    assert_eq!((entry.solve)(&input, Part::Two)?, Answer::Number(12));
+
+    Ok(())
+}
+
+#[test]
+fn test_solve_line_endings() -> Result<(), AocError> {
+    registry().iter().try_for_each(|e| {
+        let input = InputSource::sample(e.day).unwrap().read()?;
+        let variants = [
+            format!("{}\n", input),
+            format!("{}\n\n\n", input),
+            format!("{}\r\n", input.replace('\n', "\r\n")),
+        ];
+
+        Part::ALL.into_iter().try_for_each(|part| {
+            let expected = (e.solve)(&input, part)?;
+            variants.iter().try_for_each(|v| {
+                assert_eq!((e.solve)(v, part)?, expected);
+
+                Ok(())
+            })
+        })
+    })
+}
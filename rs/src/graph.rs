@@ -0,0 +1,506 @@
+use crate::{AocError, Solution};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+// Graph is a graph of nodes of type N joined by edges carrying values of type E.  Nodes are
+// interned as they are added so that they are referred to by a small NodeId and the edges of each
+// node are kept in an adjacency list.  The edges of an undirected graph are held in both
+// directions.
+#[derive(Debug, Clone)]
+pub struct Graph<N, E> {
+    nodes: Vec<N>,
+    ids: HashMap<N, NodeId>,
+    edges: Vec<Vec<(NodeId, E)>>,
+    directed: bool,
+}
+
+// NodeId is the id of a node in a graph (the order in which the nodes were added).
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NodeId(pub usize);
+
+impl NodeId {
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+impl<N: Clone + Hash + Eq, E: Clone> Graph<N, E> {
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Self {
+            nodes: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+            directed,
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    // The id of a node, adding the node if it is not in the graph yet.
+    pub fn add_node(&mut self, node: N) -> NodeId {
+        if let Some(id) = self.ids.get(&node) {
+            return *id;
+        }
+
+        let id = NodeId(self.nodes.len());
+        self.ids.insert(node.clone(), id);
+        self.nodes.push(node);
+        self.edges.push(vec![]);
+
+        id
+    }
+
+    // Add an edge between two nodes (adding the nodes if they are not in the graph yet) and
+    // return their ids.
+    pub fn add_edge(&mut self, from: N, to: N, edge: E) -> (NodeId, NodeId) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.add_edge_ids(from, to, edge);
+
+        (from, to)
+    }
+
+    pub fn add_edge_ids(&mut self, from: NodeId, to: NodeId, edge: E) {
+        if !self.directed && from != to {
+            self.edges[to.0].push((from, edge.clone()));
+        }
+        self.edges[from.0].push((to, edge));
+    }
+
+    pub fn id(&self, node: &N) -> Option<NodeId> {
+        self.ids.get(node).copied()
+    }
+
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id.0]
+    }
+
+    // The nodes in the order they were added.
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeId, &N)> + '_ {
+        self.nodes.iter().enumerate().map(|(i, n)| (NodeId(i), n))
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    // The edges leaving a node (in the order they were added).
+    pub fn edges(&self, id: NodeId) -> impl Iterator<Item = (NodeId, &E)> + '_ {
+        self.edges[id.0].iter().map(|(to, e)| (*to, e))
+    }
+
+    pub fn neighbors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges[id.0].iter().map(|(to, _)| *to)
+    }
+
+    // The nodes reachable from a node in depth first (pre)order.
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut rv = vec![];
+        let mut seen = vec![false; self.len()];
+        let mut stack = vec![start];
+
+        while let Some(id) = stack.pop() {
+            if seen[id.0] {
+                continue;
+            }
+            seen[id.0] = true;
+            rv.push(id);
+
+            // push in reverse so that the first edge is followed first
+            stack.extend(
+                self.neighbors(id)
+                    .filter(|n| !seen[n.0])
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .rev(),
+            );
+        }
+
+        rv
+    }
+
+    // The nodes reachable from a node in breadth first order.
+    pub fn bfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut rv = vec![];
+        let mut seen = vec![false; self.len()];
+        let mut queue = VecDeque::from([start]);
+        seen[start.0] = true;
+
+        while let Some(id) = queue.pop_front() {
+            rv.push(id);
+
+            self.neighbors(id).for_each(|n| {
+                if !seen[n.0] {
+                    seen[n.0] = true;
+                    queue.push_back(n);
+                }
+            });
+        }
+
+        rv
+    }
+
+    // The nodes ordered so that every edge goes from an earlier node to a later one (None if the
+    // graph has a cycle, which every edge of an undirected graph is).
+    pub fn topological_sort(&self) -> Option<Vec<NodeId>> {
+        let mut incoming = vec![0; self.len()];
+        self.edges
+            .iter()
+            .flatten()
+            .for_each(|(to, _)| incoming[to.0] += 1);
+
+        let mut ready: VecDeque<NodeId> = (0..self.len())
+            .filter(|i| incoming[*i] == 0)
+            .map(NodeId)
+            .collect();
+
+        let mut rv = vec![];
+        while let Some(id) = ready.pop_front() {
+            rv.push(id);
+
+            self.neighbors(id).for_each(|n| {
+                incoming[n.0] -= 1;
+                if incoming[n.0] == 0 {
+                    ready.push_back(n);
+                }
+            });
+        }
+
+        if rv.len() == self.len() {
+            Some(rv)
+        } else {
+            None
+        }
+    }
+
+    // The cheapest paths from a node to every other node (Dijkstra) where the cost of each edge is
+    // given by a function of its value.
+    pub fn shortest_paths<F>(&self, start: NodeId, mut cost: F) -> ShortestPaths
+    where
+        F: FnMut(&E) -> usize,
+    {
+        let mut distances = vec![None; self.len()];
+        let mut previous = vec![None; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((0, start))]);
+        distances[start.0] = Some(0);
+
+        while let Some(Reverse((dist, id))) = queue.pop() {
+            if distances[id.0].is_some_and(|d| d < dist) {
+                continue;
+            }
+
+            self.edges(id).for_each(|(n, e)| {
+                let next = dist + cost(e);
+                if distances[n.0].is_none_or(|d| next < d) {
+                    distances[n.0] = Some(next);
+                    previous[n.0] = Some(id);
+                    queue.push(Reverse((next, n)));
+                }
+            });
+        }
+
+        ShortestPaths {
+            start,
+            distances,
+            previous,
+        }
+    }
+
+    // The strongly connected components (Tarjan), each a set of nodes that can all reach each
+    // other, in reverse topological order of the components.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        let mut tarjan = Tarjan {
+            index: vec![None; self.len()],
+            low: vec![0; self.len()],
+            on_stack: vec![false; self.len()],
+            stack: vec![],
+            next: 0,
+            components: vec![],
+        };
+
+        (0..self.len()).for_each(|i| {
+            if tarjan.index[i].is_none() {
+                tarjan.visit(self, NodeId(i));
+            }
+        });
+
+        tarjan.components
+    }
+
+    // The graph in the Graphviz DOT language with the labels of the nodes and (optionally) the
+    // edges given by functions.
+    pub fn to_dot<F, G>(&self, mut node_label: F, mut edge_label: G) -> String
+    where
+        F: FnMut(&N) -> String,
+        G: FnMut(&E) -> Option<String>,
+    {
+        let (kind, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+
+        let mut rv = format!("{} {{\n", kind);
+        self.nodes().for_each(|(id, n)| {
+            rv += format!("    n{} [label={}];\n", id.0, quote(&node_label(n))).as_str();
+        });
+        self.nodes().for_each(|(from, _)| {
+            self.edges(from)
+                // the edges of an undirected graph are only written once
+                .filter(|(to, _)| self.directed || from <= *to)
+                .for_each(|(to, e)| {
+                    rv += format!("    n{} {} n{}", from.0, arrow, to.0).as_str();
+                    if let Some(label) = edge_label(e) {
+                        rv += format!(" [label={}]", quote(&label)).as_str();
+                    }
+                    rv += ";\n";
+                })
+        });
+        rv += "}\n";
+
+        rv
+    }
+}
+
+// Parse the input of a solution and write it as a DOT graph (if the solution has a graph at all).
+pub fn dot<S: Solution>(input: &str) -> Result<Option<String>, AocError> {
+    let input = S::parse(&crate::normalize(input))?;
+
+    Ok(S::dot(&input))
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// ShortestPaths is the result of Graph::shortest_paths.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths {
+    start: NodeId,
+    distances: Vec<Option<usize>>,
+    previous: Vec<Option<NodeId>>,
+}
+
+impl ShortestPaths {
+    // The cost of the cheapest path to a node (None if the node cannot be reached).
+    pub fn distance(&self, id: NodeId) -> Option<usize> {
+        self.distances.get(id.0).copied().flatten()
+    }
+
+    // The nodes of a cheapest path from the start to a node (both included).
+    pub fn path_to(&self, id: NodeId) -> Option<Vec<NodeId>> {
+        self.distance(id)?;
+
+        let mut rv = vec![id];
+        let mut current = id;
+        while current != self.start {
+            current = self.previous[current.0]?;
+            rv.push(current);
+        }
+        rv.reverse();
+
+        Some(rv)
+    }
+}
+
+struct Tarjan {
+    index: Vec<Option<usize>>,
+    low: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<NodeId>,
+    next: usize,
+    components: Vec<Vec<NodeId>>,
+}
+
+impl Tarjan {
+    // Visit the nodes reachable from a node depth first, with an explicit stack of the nodes being
+    // visited (each with its neighbours and how many of them have been looked at) in place of
+    // recursion so that long paths cannot overflow the call stack.
+    fn visit<N: Clone + Hash + Eq, E: Clone>(&mut self, graph: &Graph<N, E>, id: NodeId) {
+        let mut calls = vec![self.enter(graph, id)];
+
+        while let Some((id, neighbors, next)) = calls.last_mut() {
+            let (id, neighbor) = (*id, neighbors.get(*next).copied());
+            *next += 1;
+
+            let v = id.0;
+            match neighbor {
+                Some(n) => match self.index[n.0] {
+                    None => calls.push(self.enter(graph, n)),
+                    Some(index) if self.on_stack[n.0] => self.low[v] = self.low[v].min(index),
+                    Some(_) => {}
+                },
+                None => {
+                    calls.pop();
+                    self.leave(id);
+                    if let Some((parent, _, _)) = calls.last() {
+                        self.low[parent.0] = self.low[parent.0].min(self.low[v]);
+                    }
+                }
+            }
+        }
+    }
+
+    // Number a node and put it on the stack, returning the call that visits its neighbours.
+    fn enter<N: Clone + Hash + Eq, E: Clone>(
+        &mut self,
+        graph: &Graph<N, E>,
+        id: NodeId,
+    ) -> (NodeId, Vec<NodeId>, usize) {
+        let v = id.0;
+        self.index[v] = Some(self.next);
+        self.low[v] = self.next;
+        self.next += 1;
+        self.stack.push(id);
+        self.on_stack[v] = true;
+
+        (id, graph.neighbors(id).collect(), 0)
+    }
+
+    // Finish a node once all of its neighbours have been visited.
+    fn leave(&mut self, id: NodeId) {
+        let v = id.0;
+
+        // the node is the root of a component
+        if Some(self.low[v]) == self.index[v] {
+            let mut component = vec![];
+            while let Some(n) = self.stack.pop() {
+                self.on_stack[n.0] = false;
+                component.push(n);
+                if n == id {
+                    break;
+                }
+            }
+            component.sort();
+
+            self.components.push(component);
+        }
+    }
+}
+
+#[test]
+fn test_traversal() {
+    let mut graph = Graph::<&str, ()>::undirected();
+    ["start-A", "start-b", "A-c", "A-b", "b-d", "A-end", "b-end"]
+        .iter()
+        .for_each(|e| {
+            let (from, to) = e.split_once('-').unwrap();
+            graph.add_edge(from, to, ());
+        });
+
+    let id = |n| graph.id(&n).unwrap();
+    let names = |ids: Vec<NodeId>| ids.into_iter().map(|i| *graph.node(i)).collect::<Vec<_>>();
+
+    assert_eq!(graph.len(), 6);
+    assert_eq!(
+        names(graph.neighbors(id("A")).collect()),
+        ["start", "c", "b", "end"]
+    );
+    assert_eq!(
+        names(graph.dfs(id("start"))),
+        ["start", "A", "c", "b", "d", "end"]
+    );
+    assert_eq!(
+        names(graph.bfs(id("start"))),
+        ["start", "A", "b", "c", "end", "d"]
+    );
+    assert_eq!(graph.topological_sort(), None);
+
+    let paths = graph.shortest_paths(id("c"), |_| 1);
+    assert_eq!(paths.distance(id("d")), Some(3));
+    assert_eq!(names(paths.path_to(id("d")).unwrap()), ["c", "A", "b", "d"]);
+
+    assert_eq!(graph.strongly_connected_components().len(), 1);
+}
+
+#[test]
+fn test_directed() {
+    let mut graph = Graph::<char, usize>::directed();
+    [
+        ('a', 'b', 4),
+        ('a', 'c', 1),
+        ('c', 'b', 2),
+        ('b', 'd', 5),
+        ('d', 'e', 1),
+        ('e', 'd', 1),
+    ]
+    .into_iter()
+    .for_each(|(from, to, cost)| {
+        graph.add_edge(from, to, cost);
+    });
+    let id = |n| graph.id(&n).unwrap();
+
+    let paths = graph.shortest_paths(id('a'), |c| *c);
+    assert_eq!(paths.distance(id('b')), Some(3));
+    assert_eq!(paths.path_to(id('e')).unwrap().len(), 5);
+    assert_eq!(
+        graph.shortest_paths(id('d'), |c| *c).distance(id('a')),
+        None
+    );
+
+    // d and e form a cycle
+    assert_eq!(graph.topological_sort(), None);
+    assert_eq!(
+        graph.strongly_connected_components(),
+        vec![
+            vec![id('d'), id('e')],
+            vec![id('b')],
+            vec![id('c')],
+            vec![id('a')]
+        ]
+    );
+
+    // a long cycle is a single component (and too deep to visit recursively)
+    let mut ring = Graph::<usize, ()>::directed();
+    (0..100_000).for_each(|i| {
+        ring.add_edge(i, (i + 1) % 100_000, ());
+    });
+    let components = ring.strongly_connected_components();
+    assert_eq!(components.len(), 1);
+    assert_eq!(components[0].len(), 100_000);
+
+    let mut dag = Graph::<char, ()>::directed();
+    dag.add_edge('x', 'z', ());
+    dag.add_edge('y', 'x', ());
+    let order: Vec<_> = dag
+        .topological_sort()
+        .unwrap()
+        .into_iter()
+        .map(|i| *dag.node(i))
+        .collect();
+    assert_eq!(order, ['y', 'x', 'z']);
+}
+
+#[test]
+fn test_dot() {
+    let mut graph = Graph::<&str, usize>::undirected();
+    graph.add_edge("start", "A", 1);
+    graph.add_edge("A", "end", 2);
+
+    assert_eq!(
+        graph.to_dot(|n| n.to_string(), |e| Some(e.to_string())),
+        "graph {\n    n0 [label=\"start\"];\n    n1 [label=\"A\"];\n    n2 [label=\"end\"];\n    n0 -- n1 [label=\"1\"];\n    n1 -- n2 [label=\"2\"];\n}\n"
+    );
+
+    let mut graph = Graph::<&str, ()>::directed();
+    graph.add_edge("a\"b", "c", ());
+    assert_eq!(
+        graph.to_dot(|n| n.to_string(), |_| None),
+        "digraph {\n    n0 [label=\"a\\\"b\"];\n    n1 [label=\"c\"];\n    n0 -> n1;\n}\n"
+    );
+}
@@ -1,140 +1,481 @@
+use crate::grid_nd::{Boundary, GridND, GridNDIter, GridNDIterMut, GridNDPoint};
+use crate::pos::{Pos, Vec2};
 use crate::AocError;
 
 // Leverage https://github.com/mlhoyt/aoc2020/blob/main/rs/src/bin/day11part2.rs "Layout"
 // 2D grid abstraction.
+//
+// The values are held by a GridND with [y, x] coordinates; Grid2D adds the (y, x) and Pos based
+// API, the transforms and the line walks that only make sense in two dimensions.
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid2D<T: Copy> {
-    grid: Vec<T>,
-    width: usize,
-    height: usize,
+    grid: GridND<T, 2>,
 }
 
 impl<T: Copy> Grid2D<T> {
-    fn init() -> Self {
+    // A grid with every point set to the same value.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
         Self {
-            grid: vec![],
-            width: 0,
-            height: 0,
+            grid: GridND::filled([height, width], value),
         }
     }
 
     pub fn new(rows: &[Vec<T>]) -> Result<Self, AocError> {
-        let mut rv = Self::init();
+        let mut cells = vec![];
+        let mut width = 0;
 
         for (i, r) in rows.iter().enumerate() {
             if i == 0 {
-                rv.width = r.len();
-            } else if rv.width != r.len() {
-                return Err(AocError::new(
+                width = r.len();
+            } else if width != r.len() {
+                return Err(AocError::invalid_input(
                     format!(
                         "row {} has length {} which does not match the previous length {}",
                         i,
                         r.len(),
-                        rv.width
+                        width
                     )
                     .as_str(),
                 ));
             }
 
-            r.iter().for_each(|v| rv.grid.push(*v));
+            cells.extend(r.iter().copied());
+        }
+
+        Ok(Self {
+            grid: GridND::from_vec([rows.len(), width], cells)?,
+        })
+    }
+
+    // Build a grid from the characters of an input (one row per line) by mapping each character
+    // to a cell value.  Invalid characters and rows of uneven width are reported with their row
+    // and column (both counted from 1).
+    pub fn parse_with<F>(input: &str, mut f: F) -> Result<Self, AocError>
+    where
+        F: FnMut(char) -> Result<T, AocError>,
+    {
+        let mut cells = vec![];
+        let mut grid_width = 0;
+        let mut height = 0;
+
+        for (y, l) in input.lines().enumerate() {
+            let mut width = 0;
+            for (x, ch) in l.chars().enumerate() {
+                let v = f(ch).map_err(|e| AocError::InvalidInput {
+                    details: format!("invalid cell {:?} at row {}, column {}", ch, y + 1, x + 1),
+                    source: Some(Box::new(e)),
+                })?;
+
+                cells.push(v);
+                width += 1;
+            }
+
+            if y == 0 {
+                grid_width = width;
+            } else if width != grid_width {
+                return Err(AocError::invalid_input(
+                    format!(
+                        "row {} has width {} which does not match the width {} of row 1",
+                        y + 1,
+                        width,
+                        grid_width
+                    )
+                    .as_str(),
+                ));
+            }
+
+            height += 1;
+        }
 
-            rv.height += 1;
+        if grid_width == 0 {
+            return Err(AocError::invalid_input("grid is empty"));
         }
 
-        Ok(rv)
+        Ok(Self {
+            grid: GridND::from_vec([height, grid_width], cells)?,
+        })
+    }
+
+    // The grid with another boundary (what the grid holds beyond its edges for lookups and
+    // neighbours).
+    pub fn with_boundary(mut self, boundary: Boundary<T>) -> Self {
+        self.grid.set_boundary(boundary);
+        self
+    }
+
+    pub fn boundary(&self) -> Boundary<T> {
+        self.grid.boundary()
+    }
+
+    pub fn set_boundary(&mut self, boundary: Boundary<T>) {
+        self.grid.set_boundary(boundary);
+    }
+
+    pub fn width(&self) -> usize {
+        self.grid.dims()[1]
+    }
+
+    pub fn height(&self) -> usize {
+        self.grid.dims()[0]
     }
 
     pub fn get_yx(&self, y: usize, x: usize) -> Option<T> {
-        match self.yx_to_index(y, x) {
-            None => None,
-            Some(i) => Some(self.grid[i]),
+        self.grid.get([y, x])
+    }
+
+    pub fn get_mut_yx(&mut self, y: usize, x: usize) -> Option<&mut T> {
+        self.grid.get_mut([y, x])
+    }
+
+    // Set the value of a point, returning the previous value (or None if the point is outside of
+    // the grid).
+    pub fn set_yx(&mut self, y: usize, x: usize, v: T) -> Option<T> {
+        self.get_mut_yx(y, x).map(|p| std::mem::replace(p, v))
+    }
+
+    // Whether a position is within the grid.
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.get(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<T> {
+        pos.to_yx().and_then(|(y, x)| self.get_yx(y, x))
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        pos.to_yx().and_then(|(y, x)| self.get_mut_yx(y, x))
+    }
+
+    // The point at a position (None if the position is outside of the grid).
+    pub fn point(&self, pos: Pos) -> Option<Grid2DPoint<T>> {
+        let (y, x) = pos.to_yx()?;
+
+        self.get_yx(y, x).map(|value| Grid2DPoint { x, y, value })
+    }
+
+    // Set the value of a position, returning the previous value (or None if the position is
+    // outside of the grid).
+    pub fn set(&mut self, pos: Pos, v: T) -> Option<T> {
+        self.get_mut(pos).map(|p| std::mem::replace(p, v))
+    }
+
+    // The value at any position under the boundary of the grid (unlike get, which is None for
+    // every position outside of the grid).
+    pub fn lookup(&self, pos: Pos) -> Option<T> {
+        self.grid.lookup([pos.y, pos.x])
+    }
+
+    // Transform every value into a new grid of the same dimensions.
+    pub fn map<U: Copy, F: FnMut(T) -> U>(&self, f: F) -> Grid2D<U> {
+        Grid2D::<U> {
+            grid: self.grid.map(f),
         }
     }
 
-    fn set_yx(&mut self, y: usize, x: usize, v: T) -> Option<usize> {
-        match self.yx_to_index(y, x) {
-            Some(i) => {
-                self.grid[i] = v;
-                Some(i)
-            }
-            _ => None,
+    // A grid with the value of every point given by a function of its (y, x) position.
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Self {
+            grid: GridND::from_fn([height, width], |[y, x]| f(y, x)),
         }
     }
 
-    fn index_to_yx(&self, i: usize) -> (usize, usize) {
-        let row = (i / self.width) as usize;
-        let col = (i % self.width) as usize;
+    // Swap the rows and columns.
+    pub fn transpose(&self) -> Self {
+        self.derived(self.height(), self.width(), |y, x| self.at(x, y))
+    }
+
+    // Rotate by 90 degrees clockwise.
+    pub fn rotate_right(&self) -> Self {
+        self.derived(self.height(), self.width(), |y, x| {
+            self.at(self.height() - 1 - x, y)
+        })
+    }
 
-        (row, col)
+    // Rotate by 90 degrees counterclockwise.
+    pub fn rotate_left(&self) -> Self {
+        self.derived(self.height(), self.width(), |y, x| {
+            self.at(x, self.width() - 1 - y)
+        })
     }
 
-    fn yx_to_index(&self, y: usize, x: usize) -> Option<usize> {
-        if y < (self.height) && x < (self.width) {
-            let n = (y * self.width) + (x);
-            Some(n)
-        } else {
-            None
+    // Mirror left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        self.derived(self.width(), self.height(), |y, x| {
+            self.at(y, self.width() - 1 - x)
+        })
+    }
+
+    // Mirror top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        self.derived(self.width(), self.height(), |y, x| {
+            self.at(self.height() - 1 - y, x)
+        })
+    }
+
+    // The part of the grid with its top left at a position (None if it does not fit in the grid).
+    pub fn crop(&self, top_left: Pos, width: usize, height: usize) -> Option<Self> {
+        let (top, left) = top_left.to_yx()?;
+        if top + height > self.height() || left + width > self.width() {
+            return None;
         }
+
+        Some(self.derived(width, height, |y, x| self.at(top + y, left + x)))
+    }
+
+    // Fold the part of the grid below row `at` up over the part above it.  The row itself is
+    // dropped, points that land on each other are combined with the merge function and the
+    // result is as tall as the taller of the two parts (with the fold along its bottom edge).  A
+    // fold beyond the bottom edge leaves the grid unchanged.
+    pub fn fold_y<F>(&self, at: usize, mut merge: F) -> Self
+    where
+        F: FnMut(T, T) -> T,
+    {
+        let at = at.min(self.height());
+        let above = at;
+        let below = self.height().saturating_sub(at + 1);
+        let height = above.max(below);
+
+        self.derived(self.width(), height, |y, x| {
+            // the rows that land on row y counted from the fold
+            let distance = height - y;
+            let upper = (distance <= above).then(|| self.at(at - distance, x));
+            let lower = (distance <= below).then(|| self.at(at + distance, x));
+
+            match (upper, lower) {
+                (Some(u), Some(l)) => merge(u, l),
+                (Some(v), None) | (None, Some(v)) => v,
+                (None, None) => unreachable!(),
+            }
+        })
+    }
+
+    // Fold the part of the grid right of column `at` over the part left of it (see fold_y).
+    pub fn fold_x<F>(&self, at: usize, merge: F) -> Self
+    where
+        F: FnMut(T, T) -> T,
+    {
+        self.transpose().fold_y(at, merge).transpose()
+    }
+
+    // A grid derived from this one (with the same boundary).
+    fn derived<F>(&self, width: usize, height: usize, f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Self::from_fn(width, height, f).with_boundary(self.boundary())
+    }
+
+    fn at(&self, y: usize, x: usize) -> T {
+        self.grid.get([y, x]).unwrap()
     }
 
-    pub fn iter(&self) -> Grid2DIter<T> {
+    // The points around a point under the boundary of the grid (wrapped around the edges for
+    // Boundary::Wrap and otherwise only those within the grid).
+    pub fn neighbors(
+        &self,
+        y: usize,
+        x: usize,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = Grid2DPoint<T>> + '_ {
+        neighborhood.offsets().iter().filter_map(move |(dy, dx)| {
+            let [y, x] = self.grid.resolve([y as isize + dy, x as isize + dx])?;
+
+            self.get_yx(y, x).map(|value| Grid2DPoint { x, y, value })
+        })
+    }
+
+    // The values around a point under the boundary of the grid (including the value of a
+    // Boundary::Default beyond the edges).
+    pub fn neighbor_values(
+        &self,
+        y: usize,
+        x: usize,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = T> + '_ {
+        neighborhood
+            .offsets()
+            .iter()
+            .filter_map(move |(dy, dx)| self.grid.lookup([y as isize + dy, x as isize + dx]))
+    }
+
+    // The (up to) 4 points above, left, right and below a point.
+    pub fn neighbors4(&self, y: usize, x: usize) -> impl Iterator<Item = Grid2DPoint<T>> + '_ {
+        self.neighbors(y, x, Neighborhood::Four)
+    }
+
+    // The (up to) 8 points surrounding a point, including the diagonals.
+    pub fn neighbors8(&self, y: usize, x: usize) -> impl Iterator<Item = Grid2DPoint<T>> + '_ {
+        self.neighbors(y, x, Neighborhood::Eight)
+    }
+
+    // The values of a row (empty if the row is outside of the grid).
+    pub fn row(&self, y: usize) -> impl Iterator<Item = T> + '_ {
+        let x_range = if y < self.height() {
+            0..self.width()
+        } else {
+            0..0
+        };
+
+        x_range.map(move |x| self.at(y, x))
+    }
+
+    // The values of a column (empty if the column is outside of the grid).
+    pub fn col(&self, x: usize) -> impl Iterator<Item = T> + '_ {
+        let y_range = if x < self.width() {
+            0..self.height()
+        } else {
+            0..0
+        };
+
+        y_range.map(move |y| self.at(y, x))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = T> + '_> + '_ {
+        (0..self.height()).map(move |y| self.row(y))
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = T> + '_> + '_ {
+        (0..self.width()).map(move |x| self.col(x))
+    }
+
+    // The values from the top left corner down and to the right.
+    pub fn diagonal(&self) -> impl Iterator<Item = T> + '_ {
+        self.ray(Pos::ORIGIN, Vec2::new(1, 1)).map(|p| p.value)
+    }
+
+    // The values from the top right corner down and to the left.
+    pub fn anti_diagonal(&self) -> impl Iterator<Item = T> + '_ {
+        let top_right = Pos::new(self.width() as isize - 1, 0);
+
+        self.ray(top_right, Vec2::new(-1, 1)).map(|p| p.value)
+    }
+
+    // The points from a position onwards in steps of a vector until the edge of the grid.
+    pub fn ray(&self, from: Pos, step: Vec2) -> impl Iterator<Item = Grid2DPoint<T>> + '_ {
+        let steps = if step == Vec2::ZERO { 1 } else { usize::MAX };
+
+        from.ray(step).take(steps).map_while(|p| self.point(p))
+    }
+
+    // The points around a position under the boundary of the grid.
+    pub fn neighbors_of(
+        &self,
+        pos: Pos,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = Grid2DPoint<T>> + '_ {
+        pos.to_yx()
+            .into_iter()
+            .flat_map(move |(y, x)| self.neighbors(y, x, neighborhood))
+    }
+
+    pub fn iter(&self) -> Grid2DIter<'_, T> {
         Grid2DIter::<T> {
-            grid: self,
-            index: 0,
+            points: self.grid.iter(),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> Grid2DIterMut<'_, T> {
+        Grid2DIterMut::<T> {
+            points: self.grid.iter_mut(),
+        }
+    }
+}
+
+// Neighborhood is a set of (dy, dx) offsets that define which points are adjacent to a point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood {
+    // Above, left, right and below (in that order).
+    Four,
+    // Four plus the diagonals (in row order).
+    Eight,
+    // Four plus the point itself.
+    FourWithSelf,
+    // Eight plus the point itself.
+    EightWithSelf,
+    // Any other set of offsets.
+    Custom(&'static [(isize, isize)]),
+}
+
+impl Neighborhood {
+    pub fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Self::Four => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Self::Eight => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+            Self::FourWithSelf => &[(-1, 0), (0, -1), (0, 0), (0, 1), (1, 0)],
+            Self::EightWithSelf => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 0),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+            Self::Custom(offsets) => offsets,
         }
     }
 }
 
 pub struct Grid2DIter<'a, T: Copy> {
-    grid: &'a Grid2D<T>,
-    index: usize,
+    points: GridNDIter<'a, T, 2>,
 }
 
 impl<'a, T: Copy> Iterator for Grid2DIter<'a, T> {
     type Item = Grid2DPoint<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.grid.grid.len() {
-            None
-        } else {
-            let pos = self.grid.index_to_yx(self.index);
-            self.index += 1;
-
-            Some(Self::Item {
-                x: pos.1,
-                y: pos.0,
-                value: self.grid.get_yx(pos.0, pos.1).unwrap(),
-            })
-        }
+        self.points.next().map(|p| Self::Item {
+            x: p.coords[1],
+            y: p.coords[0],
+            value: p.value,
+        })
     }
 }
 
-impl<T: Copy + Default> std::iter::FromIterator<Grid2DPoint<T>> for Grid2D<T> {
-    fn from_iter<I: std::iter::IntoIterator<Item = Grid2DPoint<T>>>(iter: I) -> Self {
-        let mut max_x = 0;
-        let mut max_y = 0;
-        let mut ps = vec![];
-
-        // determine width and height and capture points
-        for i in iter {
-            max_x = max_x.max(i.x);
-            max_y = max_y.max(i.y);
-            ps.push(i);
-        }
+pub struct Grid2DIterMut<'a, T: Copy> {
+    points: GridNDIterMut<'a, T, 2>,
+}
 
-        // initialize Grid2D with discovered width and height
-        let mut rv = Grid2D::<T>::init();
-        rv.width = max_x + 1;
-        rv.height = max_y + 1;
-        rv.grid = vec![T::default(); rv.width * rv.height];
+impl<'a, T: Copy> Iterator for Grid2DIterMut<'a, T> {
+    type Item = Grid2DPointMut<'a, T>;
 
-        // populate Grid2D values with captured points
-        ps.iter().for_each(|p| {
-            rv.set_yx(p.y, p.x, p.value);
-        });
+    fn next(&mut self) -> Option<Self::Item> {
+        self.points.next().map(|p| Self::Item {
+            x: p.coords[1],
+            y: p.coords[0],
+            value: p.value,
+        })
+    }
+}
 
-        rv
+// A grid just large enough to hold the points (from (0, 0)) with the points that are not given
+// set to the default value.
+impl<T: Copy + Default> std::iter::FromIterator<Grid2DPoint<T>> for Grid2D<T> {
+    fn from_iter<I: std::iter::IntoIterator<Item = Grid2DPoint<T>>>(iter: I) -> Self {
+        Self {
+            grid: iter
+                .into_iter()
+                .map(|p| GridNDPoint {
+                    coords: [p.y, p.x],
+                    value: p.value,
+                })
+                .collect(),
+        }
     }
 }
 
@@ -144,3 +485,287 @@ pub struct Grid2DPoint<T> {
     pub y: usize,
     pub value: T,
 }
+
+impl<T> Grid2DPoint<T> {
+    pub fn pos(&self) -> Pos {
+        Pos::from_yx(self.y, self.x)
+    }
+}
+
+// Grid2DPointMut is a point of a grid with a mutable reference to its value.
+pub struct Grid2DPointMut<'a, T> {
+    pub x: usize,
+    pub y: usize,
+    pub value: &'a mut T,
+}
+
+#[test]
+fn test_parse_with_digits() -> Result<(), AocError> {
+    let grid = Grid2D::parse_with("219\n398", |ch| {
+        ch.to_digit(10)
+            .map(|d| d as usize)
+            .ok_or_else(|| AocError::invalid_input("expected a digit"))
+    })?;
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get_yx(1, 2), Some(8));
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_with_enum() -> Result<(), AocError> {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Cell {
+        Open,
+        Wall,
+    }
+
+    let cell = |ch| match ch {
+        '.' => Ok(Cell::Open),
+        '#' => Ok(Cell::Wall),
+        _ => Err(AocError::invalid_input("expected '.' or '#'")),
+    };
+
+    let grid = Grid2D::parse_with("#.#\r\n...", cell)?;
+    assert_eq!(grid.get_yx(0, 0), Some(Cell::Wall));
+    assert_eq!(grid.get_yx(1, 1), Some(Cell::Open));
+
+    let err = Grid2D::parse_with("#.#\n.x.", cell).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "invalid input: invalid cell 'x' at row 2, column 2"
+    );
+    assert!(std::error::Error::source(&err).is_some());
+
+    let err = Grid2D::parse_with("#.#\n..", cell).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "invalid input: row 2 has width 2 which does not match the width 3 of row 1"
+    );
+
+    assert!(Grid2D::parse_with("", cell).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_mutation() -> Result<(), AocError> {
+    let mut grid = crate::parsers::digit_grid("123\n456")?;
+
+    assert_eq!(grid.set_yx(0, 0, 9), Some(1));
+    assert_eq!(grid.set_yx(2, 0, 9), None);
+    *grid.get_mut_yx(1, 2).unwrap() += 1;
+    grid.iter_mut()
+        .filter(|p| p.y == 1 && p.x == 0)
+        .for_each(|p| *p.value = 0);
+
+    let values: Vec<_> = grid.iter().map(|p| p.value).collect();
+    assert_eq!(values, vec![9, 2, 3, 0, 5, 7]);
+
+    let odd = grid.map(|v| v % 2 == 1);
+    assert_eq!((odd.width(), odd.height()), (3, 2));
+    assert_eq!(odd.get_yx(0, 0), Some(true));
+    assert_eq!(odd.get_yx(1, 0), Some(false));
+
+    Ok(())
+}
+
+#[test]
+fn test_neighbors() -> Result<(), AocError> {
+    let grid = crate::parsers::digit_grid("123\n456\n789")?;
+    let values = |ps: Vec<Grid2DPoint<usize>>| ps.into_iter().map(|p| p.value).collect::<Vec<_>>();
+
+    assert_eq!(values(grid.neighbors4(1, 1).collect()), vec![2, 4, 6, 8]);
+    assert_eq!(values(grid.neighbors4(0, 0).collect()), vec![2, 4]);
+    assert_eq!(values(grid.neighbors8(0, 2).collect()), vec![2, 5, 6]);
+    assert_eq!(
+        values(grid.neighbors(2, 2, Neighborhood::EightWithSelf).collect()),
+        vec![5, 6, 8, 9]
+    );
+    assert_eq!(
+        values(
+            grid.neighbors(0, 0, Neighborhood::Custom(&[(2, 2), (3, 3)]))
+                .collect()
+        ),
+        vec![9]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_pos_access() -> Result<(), AocError> {
+    let mut grid = crate::parsers::digit_grid("123\n456")?;
+
+    assert!(grid.contains(Pos::new(2, 1)));
+    assert!(!grid.contains(Pos::new(-1, 0)));
+    assert_eq!(grid.get(Pos::new(2, 1)), Some(6));
+    assert_eq!(grid.get(Pos::new(3, 1)), None);
+    assert_eq!(grid.set(Pos::new(0, 1), 0), Some(4));
+    *grid.get_mut(Pos::new(1, 0)).unwrap() += 5;
+    assert_eq!(grid.get_yx(1, 0), Some(0));
+    assert_eq!(grid.get_yx(0, 1), Some(7));
+
+    let ps: Vec<Pos> = grid
+        .neighbors_of(Pos::new(0, 0), Neighborhood::Four)
+        .map(|p| p.pos())
+        .collect();
+    assert_eq!(ps, vec![Pos::new(1, 0), Pos::new(0, 1)]);
+    assert_eq!(
+        grid.neighbors_of(Pos::new(-1, 0), Neighborhood::Four)
+            .count(),
+        0
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_transforms() -> Result<(), AocError> {
+    let grid = crate::parsers::digit_grid("123\n456")?;
+    let rows = |g: &Grid2D<usize>| {
+        (0..g.height())
+            .map(|y| (0..g.width()).map(|x| g.at(y, x)).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        rows(&grid.transpose()),
+        vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+    );
+    assert_eq!(
+        rows(&grid.rotate_right()),
+        vec![vec![4, 1], vec![5, 2], vec![6, 3]]
+    );
+    assert_eq!(
+        rows(&grid.rotate_left()),
+        vec![vec![3, 6], vec![2, 5], vec![1, 4]]
+    );
+    assert_eq!(
+        rows(&grid.flip_horizontal()),
+        vec![vec![3, 2, 1], vec![6, 5, 4]]
+    );
+    assert_eq!(
+        rows(&grid.flip_vertical()),
+        vec![vec![4, 5, 6], vec![1, 2, 3]]
+    );
+    assert_eq!(
+        rows(&grid.rotate_right().rotate_right()),
+        rows(&grid.flip_horizontal().flip_vertical())
+    );
+
+    assert_eq!(
+        grid.crop(Pos::new(1, 0), 2, 2).map(|g| rows(&g)),
+        Some(vec![vec![2, 3], vec![5, 6]])
+    );
+    assert!(grid.crop(Pos::new(2, 0), 2, 2).is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_folds() -> Result<(), AocError> {
+    let grid = crate::parsers::digit_grid("100\n000\n010\n000\n001")?;
+    let rows = |g: &Grid2D<usize>| {
+        (0..g.height())
+            .map(|y| (0..g.width()).map(|x| g.at(y, x)).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        rows(&grid.fold_y(2, |a, b| a + b)),
+        vec![vec![1, 0, 1], vec![0, 0, 0]]
+    );
+    // the part below the fold is taller than the part above it
+    assert_eq!(
+        rows(&grid.fold_y(1, |a, b| a + b)),
+        vec![vec![0, 0, 1], vec![0, 0, 0], vec![1, 1, 0]]
+    );
+    assert_eq!(
+        rows(&grid.fold_x(1, |a, b| a.max(b))),
+        vec![vec![1], vec![0], vec![0], vec![0], vec![1]]
+    );
+
+    // a fold along the edge drops the last row or column and one beyond it changes nothing
+    assert_eq!(
+        rows(&grid.fold_y(4, |a, b| a + b)),
+        vec![vec![1, 0, 0], vec![0, 0, 0], vec![0, 1, 0], vec![0, 0, 0]]
+    );
+    assert_eq!(
+        rows(&grid.fold_x(2, |a, b| a + b)),
+        vec![vec![1, 0], vec![0, 0], vec![0, 1], vec![0, 0], vec![0, 0]]
+    );
+    assert_eq!(grid.fold_y(5, |a, b| a + b), grid);
+    assert_eq!(grid.fold_x(9, |a, b| a + b), grid);
+    assert_eq!(
+        Grid2D::filled(2, 2, false).fold_y(5, |a, b| a || b),
+        Grid2D::filled(2, 2, false)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_lines() -> Result<(), AocError> {
+    let grid = crate::parsers::digit_grid("123\n456\n789")?;
+
+    assert_eq!(grid.row(1).collect::<Vec<_>>(), vec![4, 5, 6]);
+    assert_eq!(grid.col(2).collect::<Vec<_>>(), vec![3, 6, 9]);
+    assert_eq!(grid.row(3).count(), 0);
+    assert_eq!(
+        grid.rows().map(|r| r.sum()).collect::<Vec<usize>>(),
+        vec![6, 15, 24]
+    );
+    assert_eq!(
+        grid.cols().map(|c| c.sum()).collect::<Vec<usize>>(),
+        vec![12, 15, 18]
+    );
+    assert_eq!(grid.diagonal().collect::<Vec<_>>(), vec![1, 5, 9]);
+    assert_eq!(grid.anti_diagonal().collect::<Vec<_>>(), vec![3, 5, 7]);
+
+    let values = |from, step| grid.ray(from, step).map(|p| p.value).collect::<Vec<_>>();
+    assert_eq!(values(Pos::new(0, 1), Vec2::new(1, 0)), vec![4, 5, 6]);
+    assert_eq!(values(Pos::new(2, 2), Vec2::new(-1, -1)), vec![9, 5, 1]);
+    assert_eq!(values(Pos::new(1, 1), Vec2::ZERO), vec![5]);
+    assert_eq!(values(Pos::new(3, 1), Vec2::new(-1, 0)), vec![]);
+
+    Ok(())
+}
+
+#[test]
+fn test_boundaries() -> Result<(), AocError> {
+    let grid = crate::parsers::digit_grid("123\n456")?;
+    assert_eq!(grid.boundary(), Boundary::Clip);
+    assert_eq!(grid.lookup(Pos::new(-1, 0)), None);
+    assert_eq!(grid.neighbors8(0, 0).count(), 3);
+
+    let grid = grid.with_boundary(Boundary::Wrap);
+    assert_eq!(grid.lookup(Pos::new(-1, 0)), Some(3));
+    assert_eq!(grid.lookup(Pos::new(3, 3)), Some(4));
+    assert_eq!(grid.get(Pos::new(-1, 0)), None);
+    assert_eq!(
+        grid.neighbors4(0, 0).map(|p| p.value).collect::<Vec<_>>(),
+        vec![4, 3, 2, 4]
+    );
+    assert_eq!(grid.transpose().boundary(), Boundary::Wrap);
+
+    let mut grid = grid.with_boundary(Boundary::Default(0));
+    assert_eq!(grid.lookup(Pos::new(-5, 7)), Some(0));
+    assert_eq!(grid.neighbors8(0, 0).count(), 3);
+    assert_eq!(
+        grid.neighbor_values(0, 0, Neighborhood::Eight)
+            .sum::<usize>(),
+        11
+    );
+
+    grid.set_boundary(Boundary::Default(1));
+    assert_eq!(
+        grid.neighbor_values(0, 0, Neighborhood::Eight)
+            .sum::<usize>(),
+        16
+    );
+    assert_eq!(grid.map(|v| v * 2).boundary(), Boundary::Default(2));
+
+    Ok(())
+}
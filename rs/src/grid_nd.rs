@@ -0,0 +1,456 @@
+use crate::AocError;
+
+// GridND is a dense grid with any number of dimensions (e.g. the voxels of a volume or the cells
+// of a 3D or 4D automaton).  Coordinates are given from the slowest changing dimension to the
+// fastest, so [z, y, x] for a 3D grid in the same order as the (y, x) of Grid2D, and the values are
+// stored in that order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GridND<T: Copy, const N: usize> {
+    cells: Vec<T>,
+    dims: [usize; N],
+    boundary: Boundary<T>,
+}
+
+// Boundary is what a grid holds beyond its edges.  It only affects lookups by signed coordinates
+// and neighbours: get and set are always limited to the points of the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Boundary<T> {
+    // Nothing (the default).
+    Clip,
+    // The points of the opposite edge (the grid is a torus).
+    Wrap,
+    // The same value everywhere (e.g. the background of an infinite image).
+    Default(T),
+}
+
+impl<T> Boundary<T> {
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Boundary<U> {
+        match self {
+            Self::Clip => Boundary::Clip,
+            Self::Wrap => Boundary::Wrap,
+            Self::Default(v) => Boundary::Default(f(v)),
+        }
+    }
+}
+
+pub type Grid3D<T> = GridND<T, 3>;
+pub type Grid4D<T> = GridND<T, 4>;
+
+impl<T: Copy, const N: usize> GridND<T, N> {
+    // A grid with every point set to the same value.
+    pub fn filled(dims: [usize; N], value: T) -> Self {
+        Self {
+            cells: vec![value; dims.iter().product()],
+            dims,
+            boundary: Boundary::Clip,
+        }
+    }
+
+    // A grid of the given dimensions from its values in storage order.
+    pub fn from_vec(dims: [usize; N], cells: Vec<T>) -> Result<Self, AocError> {
+        let len: usize = dims.iter().product();
+        if cells.len() != len {
+            return Err(AocError::invalid_input(
+                format!(
+                    "{} values do not fill a grid of dimensions {:?} ({} values)",
+                    cells.len(),
+                    dims,
+                    len
+                )
+                .as_str(),
+            ));
+        }
+
+        Ok(Self {
+            cells,
+            dims,
+            boundary: Boundary::Clip,
+        })
+    }
+
+    // A grid with the value of every point given by a function of its coordinates.
+    pub fn from_fn<F>(dims: [usize; N], mut f: F) -> Self
+    where
+        F: FnMut([usize; N]) -> T,
+    {
+        let len: usize = dims.iter().product();
+
+        Self {
+            cells: (0..len).map(|i| f(coords_of(dims, i))).collect(),
+            dims,
+            boundary: Boundary::Clip,
+        }
+    }
+
+    // The grid with another boundary.
+    pub fn with_boundary(mut self, boundary: Boundary<T>) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    pub fn boundary(&self) -> Boundary<T> {
+        self.boundary
+    }
+
+    // Change the boundary (e.g. when the background of an image flips between steps).
+    pub fn set_boundary(&mut self, boundary: Boundary<T>) {
+        self.boundary = boundary;
+    }
+
+    // The size of each dimension (slowest changing first).
+    pub fn dims(&self) -> [usize; N] {
+        self.dims
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // Whether (possibly negative) coordinates are within the grid.
+    pub fn contains(&self, coords: [isize; N]) -> bool {
+        coords
+            .iter()
+            .zip(self.dims.iter())
+            .all(|(c, d)| usize::try_from(*c).is_ok_and(|c| c < *d))
+    }
+
+    pub fn get(&self, coords: [usize; N]) -> Option<T> {
+        self.index_of(coords).map(|i| self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, coords: [usize; N]) -> Option<&mut T> {
+        self.index_of(coords).map(|i| &mut self.cells[i])
+    }
+
+    // Set the value of a point, returning the previous value (or None if the point is outside of
+    // the grid).
+    pub fn set(&mut self, coords: [usize; N], v: T) -> Option<T> {
+        self.get_mut(coords).map(|p| std::mem::replace(p, v))
+    }
+
+    // The point that (possibly negative) coordinates refer to under the boundary of the grid:
+    // coordinates beyond the edges wrap around for Boundary::Wrap and are None otherwise.
+    pub fn resolve(&self, coords: [isize; N]) -> Option<[usize; N]> {
+        let mut rv = [0; N];
+        for ((r, c), d) in rv.iter_mut().zip(coords.iter()).zip(self.dims.iter()) {
+            *r = match self.boundary {
+                Boundary::Wrap if *d > 0 => c.rem_euclid(*d as isize) as usize,
+                _ => usize::try_from(*c).ok().filter(|c| c < d)?,
+            };
+        }
+
+        Some(rv)
+    }
+
+    // The value at (possibly negative) coordinates under the boundary of the grid.
+    pub fn lookup(&self, coords: [isize; N]) -> Option<T> {
+        match (self.resolve(coords), self.boundary) {
+            (Some(coords), _) => self.get(coords),
+            (None, Boundary::Default(v)) => Some(v),
+            (None, _) => None,
+        }
+    }
+
+    // Transform every value (including the value of a Boundary::Default) into a new grid of the
+    // same dimensions.
+    pub fn map<U: Copy, F: FnMut(T) -> U>(&self, mut f: F) -> GridND<U, N> {
+        GridND::<U, N> {
+            boundary: self.boundary.map(&mut f),
+            cells: self.cells.iter().copied().map(f).collect(),
+            dims: self.dims,
+        }
+    }
+
+    // The points around a point under the boundary of the grid (wrapped around the edges for
+    // Boundary::Wrap and otherwise only those within the grid).
+    pub fn neighbors(
+        &self,
+        coords: [usize; N],
+        adjacency: Adjacency,
+    ) -> impl Iterator<Item = GridNDPoint<T, N>> + '_ {
+        adjacency
+            .offsets::<N>()
+            .into_iter()
+            .filter_map(move |d| self.resolve(offset(coords, d)))
+            .filter_map(move |coords| self.get(coords).map(|value| GridNDPoint { coords, value }))
+    }
+
+    // The values around a point under the boundary of the grid (including the value of a
+    // Boundary::Default beyond the edges).
+    pub fn neighbor_values(
+        &self,
+        coords: [usize; N],
+        adjacency: Adjacency,
+    ) -> impl Iterator<Item = T> + '_ {
+        adjacency
+            .offsets::<N>()
+            .into_iter()
+            .filter_map(move |d| self.lookup(offset(coords, d)))
+    }
+
+    // The points of the grid in storage order.
+    pub fn iter(&self) -> GridNDIter<'_, T, N> {
+        GridNDIter {
+            values: self.cells.iter().enumerate(),
+            dims: self.dims,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> GridNDIterMut<'_, T, N> {
+        GridNDIterMut {
+            values: self.cells.iter_mut().enumerate(),
+            dims: self.dims,
+        }
+    }
+
+    fn index_of(&self, coords: [usize; N]) -> Option<usize> {
+        coords
+            .iter()
+            .zip(self.dims.iter())
+            .try_fold(0, |i, (c, d)| if c < d { Some(i * d + c) } else { None })
+    }
+}
+
+// The (possibly negative) coordinates of a point moved by an offset.
+fn offset<const N: usize>(coords: [usize; N], d: [isize; N]) -> [isize; N] {
+    let mut rv = [0; N];
+    for ((r, c), d) in rv.iter_mut().zip(coords.iter()).zip(d.iter()) {
+        *r = *c as isize + d;
+    }
+
+    rv
+}
+
+// The coordinates of the value at an index of the storage of a grid.
+fn coords_of<const N: usize>(dims: [usize; N], mut i: usize) -> [usize; N] {
+    let mut rv = [0; N];
+    for (c, d) in rv.iter_mut().zip(dims.iter()).rev() {
+        *c = i % d;
+        i /= d;
+    }
+
+    rv
+}
+
+// Adjacency defines which points of an N-dimensional grid are adjacent to a point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Adjacency {
+    // The 2N points one step along a single dimension (Neighborhood::Four in 2D).
+    Orthogonal,
+    // The 3^N - 1 points one step along any number of dimensions (Neighborhood::Eight in 2D).
+    Full,
+    // Orthogonal plus the point itself.
+    OrthogonalWithSelf,
+    // Full plus the point itself.
+    FullWithSelf,
+}
+
+impl Adjacency {
+    // The offsets of the adjacent points in storage order.
+    pub fn offsets<const N: usize>(&self) -> Vec<[isize; N]> {
+        let count = 3usize.pow(N as u32);
+
+        (0..count)
+            .map(|i| coords_of([3; N], i).map(|c| c as isize - 1))
+            .filter(|d| {
+                let moved = d.iter().filter(|c| **c != 0).count();
+
+                match self {
+                    Self::Orthogonal => moved == 1,
+                    Self::Full => moved > 0,
+                    Self::OrthogonalWithSelf => moved <= 1,
+                    Self::FullWithSelf => true,
+                }
+            })
+            .collect()
+    }
+}
+
+pub struct GridNDIter<'a, T: Copy, const N: usize> {
+    values: std::iter::Enumerate<std::slice::Iter<'a, T>>,
+    dims: [usize; N],
+}
+
+impl<'a, T: Copy, const N: usize> Iterator for GridNDIter<'a, T, N> {
+    type Item = GridNDPoint<T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.values.next().map(|(i, value)| Self::Item {
+            coords: coords_of(self.dims, i),
+            value: *value,
+        })
+    }
+}
+
+pub struct GridNDIterMut<'a, T: Copy, const N: usize> {
+    values: std::iter::Enumerate<std::slice::IterMut<'a, T>>,
+    dims: [usize; N],
+}
+
+impl<'a, T: Copy, const N: usize> Iterator for GridNDIterMut<'a, T, N> {
+    type Item = GridNDPointMut<'a, T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.values.next().map(|(i, value)| Self::Item {
+            coords: coords_of(self.dims, i),
+            value,
+        })
+    }
+}
+
+// A grid just large enough to hold the points (from the origin) with the points that are not
+// given set to the default value.
+impl<T: Copy + Default, const N: usize> std::iter::FromIterator<GridNDPoint<T, N>>
+    for GridND<T, N>
+{
+    fn from_iter<I: std::iter::IntoIterator<Item = GridNDPoint<T, N>>>(iter: I) -> Self {
+        let ps: Vec<_> = iter.into_iter().collect();
+
+        let mut dims = [1; N];
+        ps.iter().for_each(|p| {
+            dims.iter_mut()
+                .zip(p.coords.iter())
+                .for_each(|(d, c)| *d = (*d).max(c + 1))
+        });
+
+        let mut rv = Self::filled(dims, T::default());
+        ps.iter().for_each(|p| {
+            rv.set(p.coords, p.value);
+        });
+
+        rv
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct GridNDPoint<T, const N: usize> {
+    pub coords: [usize; N],
+    pub value: T,
+}
+
+// GridNDPointMut is a point of a grid with a mutable reference to its value.
+pub struct GridNDPointMut<'a, T, const N: usize> {
+    pub coords: [usize; N],
+    pub value: &'a mut T,
+}
+
+#[test]
+fn test_grid3d() -> Result<(), AocError> {
+    let mut grid = Grid3D::from_fn([2, 3, 4], |[z, y, x]| z * 100 + y * 10 + x);
+
+    assert_eq!(grid.dims(), [2, 3, 4]);
+    assert_eq!(grid.len(), 24);
+    assert_eq!(grid.get([1, 2, 3]), Some(123));
+    assert_eq!(grid.get([2, 0, 0]), None);
+    assert!(grid.contains([1, 2, 3]));
+    assert!(!grid.contains([-1, 0, 0]));
+
+    assert_eq!(grid.set([1, 0, 1], 7), Some(101));
+    *grid.get_mut([0, 0, 0]).unwrap() += 1;
+    grid.iter_mut()
+        .filter(|p| p.coords[0] == 1)
+        .for_each(|p| *p.value *= 2);
+    assert_eq!(grid.get([0, 0, 0]), Some(1));
+    assert_eq!(grid.get([1, 0, 1]), Some(14));
+
+    let points: Vec<_> = grid.iter().take(5).map(|p| p.coords).collect();
+    assert_eq!(points[4], [0, 1, 0]);
+    assert_eq!(
+        grid.map(|v| v % 2 == 0).iter().filter(|p| p.value).count(),
+        17
+    );
+
+    assert!(GridND::from_vec([2, 2], vec![1, 2, 3]).is_err());
+    assert_eq!(
+        GridND::from_vec([2, 2], vec![1, 2, 3, 4])?.get([1, 0]),
+        Some(3)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_adjacency() {
+    let grid = Grid4D::filled([3, 3, 3, 3], 0);
+
+    assert_eq!(grid.neighbors([1, 1, 1, 1], Adjacency::Full).count(), 80);
+    assert_eq!(
+        grid.neighbors([1, 1, 1, 1], Adjacency::Orthogonal).count(),
+        8
+    );
+    assert_eq!(grid.neighbors([0, 0, 0, 0], Adjacency::Full).count(), 15);
+    assert_eq!(
+        grid.neighbors([0, 0, 0, 0], Adjacency::FullWithSelf)
+            .count(),
+        16
+    );
+
+    // in 2D the adjacencies match the offsets of a Grid2D neighborhood
+    use crate::grid2d::Neighborhood;
+    let pairs = |offsets: Vec<[isize; 2]>| offsets.iter().map(|d| (d[0], d[1])).collect::<Vec<_>>();
+    assert_eq!(
+        pairs(Adjacency::Orthogonal.offsets()),
+        Neighborhood::Four.offsets()
+    );
+    assert_eq!(
+        pairs(Adjacency::Full.offsets()),
+        Neighborhood::Eight.offsets()
+    );
+    assert_eq!(
+        pairs(Adjacency::FullWithSelf.offsets()),
+        Neighborhood::EightWithSelf.offsets()
+    );
+}
+
+#[test]
+fn test_from_iter() {
+    let grid: Grid3D<usize> = vec![
+        GridNDPoint {
+            coords: [0, 1, 2],
+            value: 5,
+        },
+        GridNDPoint {
+            coords: [2, 0, 0],
+            value: 7,
+        },
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(grid.dims(), [3, 2, 3]);
+    assert_eq!(grid.get([0, 1, 2]), Some(5));
+    assert_eq!(grid.get([2, 0, 0]), Some(7));
+    assert_eq!(grid.iter().map(|p| p.value).sum::<usize>(), 12);
+}
+
+#[test]
+fn test_boundaries() {
+    let grid = Grid3D::from_fn([2, 2, 3], |[z, y, x]| z * 100 + y * 10 + x);
+    assert_eq!(grid.lookup([0, 0, -1]), None);
+    assert_eq!(grid.neighbors([0, 0, 0], Adjacency::Full).count(), 7);
+
+    let grid = grid.with_boundary(Boundary::Wrap);
+    assert_eq!(grid.resolve([-1, 2, -1]), Some([1, 0, 2]));
+    assert_eq!(grid.lookup([0, 0, -1]), Some(2));
+    assert_eq!(grid.neighbors([0, 0, 0], Adjacency::Full).count(), 26);
+    assert!(grid
+        .neighbors([0, 0, 0], Adjacency::Orthogonal)
+        .any(|p| p.coords == [0, 0, 2]));
+
+    let mut grid = grid.with_boundary(Boundary::Default(7));
+    assert_eq!(grid.lookup([0, 0, -1]), Some(7));
+    assert_eq!(grid.neighbors([0, 0, 0], Adjacency::Full).count(), 7);
+    assert_eq!(
+        grid.neighbor_values([0, 0, 0], Adjacency::Full)
+            .filter(|v| *v == 7)
+            .count(),
+        19
+    );
+
+    grid.set_boundary(Boundary::Default(8));
+    assert_eq!(grid.map(|v| v + 1).lookup([5, 5, 5]), Some(9));
+}
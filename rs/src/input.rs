@@ -0,0 +1,165 @@
+use crate::AocError;
+use std::io::Read;
+use std::path::PathBuf;
+
+// The environment variable that overrides the directory named inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+// The names of the inputs every day can have.  A "sample" input is compiled into the binary
+// while a "full" input is read from the input directory.
+pub const INPUT_NAMES: [&str; 2] = ["sample", "full"];
+
+const SAMPLES: [&str; 13] = [
+    include_str!("../input/day01.test.txt"),
+    include_str!("../input/day02.test.txt"),
+    include_str!("../input/day03.test.txt"),
+    include_str!("../input/day04.test.txt"),
+    include_str!("../input/day05.test.txt"),
+    include_str!("../input/day06.test.txt"),
+    include_str!("../input/day07.test.txt"),
+    include_str!("../input/day08.test.txt"),
+    include_str!("../input/day09.test.txt"),
+    include_str!("../input/day10.test.txt"),
+    include_str!("../input/day11.test.txt"),
+    include_str!("../input/day12.test.txt"),
+    include_str!("../input/day13.test.txt"),
+];
+
+// InputSource is where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    // An explicit file path.
+    Path(PathBuf),
+    // The standard input.
+    Stdin,
+    // A named input of a day that is read from the input directory (see input_dir).
+    Named { day: usize, name: String },
+    // An input compiled into the binary.
+    Embedded { day: usize, input: &'static str },
+}
+
+impl InputSource {
+    // The embedded sample input of a day.
+    pub fn sample(day: usize) -> Option<Self> {
+        let input = SAMPLES.get(day.checked_sub(1)?)?;
+
+        Some(Self::Embedded { day, input })
+    }
+
+    // The full input of a day (from the input directory).
+    pub fn full(day: usize) -> Self {
+        Self::Named {
+            day,
+            name: "full".to_string(),
+        }
+    }
+
+    // The source for a named input of a day.  Names other than "sample" and "full" are read from
+    // dayNN.<name>.txt in the input directory.
+    pub fn named(day: usize, name: &str) -> Self {
+        match name {
+            "sample" => Self::sample(day).unwrap_or(Self::Named {
+                day,
+                name: name.to_string(),
+            }),
+            _ => Self::Named {
+                day,
+                name: name.to_string(),
+            },
+        }
+    }
+
+    // The source for a command line argument: "-" is the standard input, one of the INPUT_NAMES
+    // is that input of the day and anything else is a file path.
+    pub fn from_arg(day: usize, arg: &str) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else if INPUT_NAMES.contains(&arg) {
+            Self::named(day, arg)
+        } else {
+            Self::Path(PathBuf::from(arg))
+        }
+    }
+
+    // Read the input, normalizing its line endings (see crate::normalize).
+    pub fn read(&self) -> Result<String, AocError> {
+        let input = match self {
+            Self::Path(path) => crate::read_file(&path.to_string_lossy())?,
+            Self::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| AocError::from(e).in_file(&self.to_string()))?;
+
+                input
+            }
+            Self::Named { .. } => crate::read_file(&self.to_string())?,
+            Self::Embedded { input, .. } => input.to_string(),
+        };
+
+        Ok(crate::normalize(&input))
+    }
+}
+
+impl std::fmt::Display for InputSource {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Path(path) => write!(fmt, "{}", path.display()),
+            Self::Stdin => write!(fmt, "<stdin>"),
+            Self::Named { day, name } => {
+                let file_name = match name.as_str() {
+                    "full" => format!("day{:02}.txt", day),
+                    "sample" => format!("day{:02}.test.txt", day),
+                    _ => format!("day{:02}.{}.txt", day, name),
+                };
+
+                write!(fmt, "{}", input_dir().join(file_name).display())
+            }
+            Self::Embedded { day, .. } => write!(fmt, "<day{:02} sample>", day),
+        }
+    }
+}
+
+// The directory named inputs are read from: $AOC_INPUT_DIR if it is set and otherwise the
+// crate's own input directory (so it does not depend on the working directory).
+pub fn input_dir() -> PathBuf {
+    match std::env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("input"),
+    }
+}
+
+#[test]
+fn test_sample() -> Result<(), AocError> {
+    let source = InputSource::sample(1).unwrap();
+    assert_eq!(source.to_string(), "<day01 sample>");
+    assert!(source.read()?.starts_with("199\n200\n"));
+
+    assert!(InputSource::sample(0).is_none());
+    assert!(InputSource::sample(14).is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_from_arg() {
+    assert_eq!(InputSource::from_arg(5, "-"), InputSource::Stdin);
+    assert_eq!(InputSource::from_arg(5, "full"), InputSource::full(5));
+    assert_eq!(
+        InputSource::from_arg(5, "sample"),
+        InputSource::sample(5).unwrap()
+    );
+    assert_eq!(
+        InputSource::from_arg(5, "input/day05.txt"),
+        InputSource::Path(PathBuf::from("input/day05.txt"))
+    );
+}
+
+#[test]
+fn test_full() -> Result<(), AocError> {
+    let source = InputSource::full(1);
+    assert!(source.to_string().ends_with("day01.txt"));
+    assert!(!source.read()?.is_empty());
+
+    Ok(())
+}
@@ -1,53 +1,324 @@
 use std::error::Error;
 use std::io::{BufRead, BufReader};
 
+pub mod answers;
+pub mod automaton;
+pub mod bench;
+pub mod cycle;
+pub mod days;
+pub mod graph;
 pub mod grid2d;
+pub mod grid_nd;
+pub mod input;
+pub mod ocr;
+pub mod parsers;
+pub mod pos;
+pub mod render;
+pub mod search;
+pub mod sparse_grid;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct AocError {
-    details: String,
+// AocError is the error type shared by the library and every day's solution.
+#[derive(Debug)]
+pub enum AocError {
+    // Reading an input failed (with the path that was being read, if known).
+    Io {
+        path: Option<String>,
+        source: std::io::Error,
+    },
+    // An input did not match a grammar (with the file the input came from, if known).
+    Parse {
+        file: Option<String>,
+        line: usize,
+        column: usize,
+        expected: Vec<String>,
+    },
+    // An input was syntactically valid but cannot be used (optionally caused by another error).
+    InvalidInput {
+        details: String,
+        source: Option<Box<dyn Error + Send + Sync>>,
+    },
+    // A valid input for which no answer exists.
+    Unsolvable(String),
 }
 
 impl AocError {
-    pub fn new(s: &str) -> Self {
-        Self {
-            details: s.to_string(),
+    pub fn invalid_input(details: &str) -> Self {
+        Self::InvalidInput {
+            details: details.to_string(),
+            source: None,
+        }
+    }
+
+    pub fn unsolvable(details: &str) -> Self {
+        Self::Unsolvable(details.to_string())
+    }
+
+    // Record the file an Io or Parse error occurred in (if it is not already known).
+    pub fn in_file(self, name: &str) -> Self {
+        match self {
+            Self::Io { path: None, source } => Self::Io {
+                path: Some(name.to_string()),
+                source,
+            },
+            Self::Parse {
+                file: None,
+                line,
+                column,
+                expected,
+            } => Self::Parse {
+                file: Some(name.to_string()),
+                line,
+                column,
+                expected,
+            },
+            _ => self,
         }
     }
 }
 
 impl std::fmt::Display for AocError {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(fmt, "{}", self.details)
+        match self {
+            Self::Io {
+                path: Some(path),
+                source,
+            } => write!(fmt, "cannot read {}: {}", path, source),
+            Self::Io { path: None, source } => write!(fmt, "cannot read input: {}", source),
+            Self::Parse {
+                file,
+                line,
+                column,
+                expected,
+            } => {
+                match file {
+                    Some(file) => write!(fmt, "{}:{}:{}: ", file, line, column)?,
+                    None => write!(fmt, "line {}, column {}: ", line, column)?,
+                }
+
+                match expected.len() {
+                    0 => write!(fmt, "unexpected input"),
+                    1 => write!(fmt, "expected {}", expected[0]),
+                    _ => write!(fmt, "expected one of {}", expected.join(", ")),
+                }
+            }
+            Self::InvalidInput { details, .. } => write!(fmt, "invalid input: {}", details),
+            Self::Unsolvable(details) => write!(fmt, "unsolvable: {}", details),
+        }
     }
 }
 
 impl std::error::Error for AocError {
-    fn description(&self) -> &str {
-        &self.details
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::InvalidInput {
+                source: Some(source),
+                ..
+            } => Some(source.as_ref()),
+            _ => None,
+        }
     }
 }
 
 impl From<std::io::Error> for AocError {
     fn from(err: std::io::Error) -> Self {
-        #[allow(deprecated)]
-        Self::new(err.description())
+        Self::Io {
+            path: None,
+            source: err,
+        }
+    }
+}
+
+impl From<std::num::ParseIntError> for AocError {
+    fn from(err: std::num::ParseIntError) -> Self {
+        Self::InvalidInput {
+            details: err.to_string(),
+            source: Some(Box::new(err)),
+        }
     }
 }
 
 impl From<peg::error::ParseError<peg::str::LineCol>> for AocError {
     fn from(err: peg::error::ParseError<peg::str::LineCol>) -> Self {
-        #[allow(deprecated)]
-        Self::new(err.description())
+        let mut expected: Vec<String> = err.expected.tokens().map(|t| t.to_string()).collect();
+        expected.sort();
+
+        Self::Parse {
+            file: None,
+            line: err.location.line,
+            column: err.location.column,
+            expected,
+        }
     }
 }
 
 pub fn read_file(name: &str) -> Result<String, AocError> {
-    let file = std::fs::File::open(name)?;
-    let mut file = BufReader::new(file);
+    let read = || -> Result<String, std::io::Error> {
+        let file = std::fs::File::open(name)?;
+        let mut file = BufReader::new(file);
+
+        let mut input = String::new();
+        while file.read_line(&mut input)? > 0 {}
+
+        Ok(input)
+    };
+
+    read().map_err(|e| AocError::from(e).in_file(name))
+}
+
+// Normalize the line endings of an input so that the day parsers only have to deal with "\n":
+// CRLF and CR line endings become LF and trailing newlines, blank lines and whitespace are
+// dropped.
+pub fn normalize(input: &str) -> String {
+    input
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .trim_end()
+        .to_string()
+}
+
+// Answer is the common result of every puzzle part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
 
-    let mut input = String::new();
-    while file.read_line(&mut input)? > 0 {}
+impl std::fmt::Display for Answer {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Number(n) => write!(fmt, "{}", n),
+            Self::Text(s) => write!(fmt, "{}", s),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(v: usize) -> Self {
+        Self::Number(v as i64)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(v: isize) -> Self {
+        Self::Number(v as i64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(v: String) -> Self {
+        Self::Text(v)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(n: usize) -> Option<Self> {
+        match n {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> usize {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(fmt, "part{}", self.number())
+    }
+}
+
+// Solution is implemented by every day: the raw input is parsed once into the day's Input type
+// and then each part is solved from it.
+pub trait Solution {
+    const DAY: usize;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+
+    fn part1(input: &Self::Input) -> Result<Answer, AocError>;
+
+    fn part2(input: &Self::Input) -> Result<Answer, AocError>;
+
+    // An image of the puzzle (e.g. a map or the state it ends in) for days that have one.
+    fn render(_input: &Self::Input) -> Option<render::Image> {
+        None
+    }
+
+    // The puzzle as a graph in the Graphviz DOT language for days that have one.
+    fn dot(_input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+// Parse the input and solve a single part of a solution.
+pub fn solve<S: Solution>(input: &str, part: Part) -> Result<Answer, AocError> {
+    let input = S::parse(&normalize(input))?;
+
+    match part {
+        Part::One => S::part1(&input),
+        Part::Two => S::part2(&input),
+    }
+}
+
+#[test]
+fn test_error_parse_location() {
+    let err: AocError = days::day05::Day05::parse("0,9 -> 5,9\n8,0 => 0,8")
+        .err()
+        .unwrap()
+        .in_file("input/day05.txt");
+
+    match &err {
+        AocError::Parse {
+            file,
+            line,
+            column,
+            expected,
+        } => {
+            assert_eq!(file.as_deref(), Some("input/day05.txt"));
+            assert_eq!((*line, *column), (2, 5));
+            assert!(expected.contains(&"\"->\"".to_string()));
+        }
+        _ => panic!("unexpected error {:?}", err),
+    }
+    assert!(err.to_string().starts_with("input/day05.txt:2:5: expected"));
+}
+
+#[test]
+fn test_error_source() {
+    let err = read_file("input/missing.txt").err().unwrap();
+    assert!(matches!(err, AocError::Io { .. }));
+    assert!(err.source().is_some());
+    assert!(err
+        .to_string()
+        .starts_with("cannot read input/missing.txt: "));
+
+    let err: AocError = "x".parse::<usize>().err().unwrap().into();
+    assert!(matches!(err, AocError::InvalidInput { .. }));
+    assert!(err.source().is_some());
+
+    assert!(AocError::unsolvable("no answer").source().is_none());
+}
 
-    Ok(input)
+#[test]
+fn test_normalize() {
+    assert_eq!(normalize("1\n2"), "1\n2");
+    assert_eq!(normalize("1\r\n2\r\n"), "1\n2");
+    assert_eq!(normalize("1\r2\r"), "1\n2");
+    assert_eq!(normalize("1\n\n2\n\n \n"), "1\n\n2");
 }
@@ -0,0 +1,83 @@
+// Recognition of the block letters that some puzzles "draw" as their answer.
+//
+// Each letter is 4 cells wide and 6 cells tall and letters are separated by a single empty
+// column, so an n letter drawing is (5 * n) - 1 cells wide.  Lit cells are '#' and unlit cells
+// are '.'.
+
+const LETTER_WIDTH: usize = 4;
+const LETTER_HEIGHT: usize = 6;
+
+const LETTERS: &[(char, [&str; LETTER_HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+// Read the letters of a drawing, returning None if the drawing is not a row of known letters.
+pub fn read_letters(drawing: &str) -> Option<String> {
+    let rows: Vec<Vec<char>> = drawing.lines().map(|l| l.chars().collect()).collect();
+    if rows.len() != LETTER_HEIGHT {
+        return None;
+    }
+
+    // pad every row to a whole number of letters (trailing unlit cells may have been trimmed)
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    let nr_letters = width.div_ceil(LETTER_WIDTH + 1);
+    if nr_letters == 0 {
+        return None;
+    }
+
+    (0..nr_letters)
+        .map(|i| {
+            let offset = i * (LETTER_WIDTH + 1);
+
+            let cells: Vec<String> = rows
+                .iter()
+                .map(|r| {
+                    (offset..(offset + LETTER_WIDTH))
+                        .map(|x| match r.get(x) {
+                            Some('#') => '#',
+                            _ => '.',
+                        })
+                        .collect()
+                })
+                .collect();
+
+            LETTERS
+                .iter()
+                .find(|(_, glyph)| glyph.iter().zip(cells.iter()).all(|(g, c)| g == c))
+                .map(|(letter, _)| *letter)
+        })
+        .collect()
+}
+
+#[test]
+fn test_read_letters() {
+    let drawing = [
+        "#..#..###",
+        "#..#...#.",
+        "####...#.",
+        "#..#...#.",
+        "#..#...#.",
+        "#..#..###",
+    ]
+    .join("\n");
+    assert_eq!(read_letters(&drawing), Some("HI".to_string()));
+
+    let drawing = ["#####", "#...#", "#...#", "#...#", "#####"].join("\n");
+    assert_eq!(read_letters(&drawing), None);
+}
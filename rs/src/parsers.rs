@@ -0,0 +1,318 @@
+use crate::grid2d::Grid2D;
+use crate::pos::Pos;
+use crate::AocError;
+
+// Parsers for the shapes of input that many puzzles share.  Each parser only parses a complete
+// input (it must consume the whole of it) and reports failures as AocError::Parse with the line and
+// column of the problem, so a day can be written by splitting its input into sections or lines and
+// handing each one to a parser here.  A grammar of its own can use them on the text a rule has
+// matched, e.g. `ns:$(['0'..='9']+) {? parsers::unsigned(ns).or(Err("number")) }`.
+
+// An unsigned integer (e.g. "42").
+pub fn unsigned(s: &str) -> Result<usize, AocError> {
+    Ok(rules::unsigned(s)?)
+}
+
+// A signed integer with an optional sign (e.g. "-42" or "+42").
+pub fn signed(s: &str) -> Result<isize, AocError> {
+    Ok(rules::signed(s)?)
+}
+
+// A comma separated list of unsigned integers (e.g. "3,4,3,1,2").
+pub fn unsigned_list(s: &str) -> Result<Vec<usize>, AocError> {
+    Ok(rules::unsigned_list(s)?)
+}
+
+// A comma separated list of signed integers (e.g. "-1,0,1").
+pub fn signed_list(s: &str) -> Result<Vec<isize>, AocError> {
+    Ok(rules::signed_list(s)?)
+}
+
+// Rows of whitespace separated unsigned integers, one row per line (e.g. a bingo board).
+pub fn unsigned_table(s: &str) -> Result<Vec<Vec<usize>>, AocError> {
+    Ok(rules::unsigned_table(s)?)
+}
+
+// Rows of whitespace separated signed integers, one row per line.
+pub fn signed_table(s: &str) -> Result<Vec<Vec<isize>>, AocError> {
+    Ok(rules::signed_table(s)?)
+}
+
+// An "x,y" point.
+pub fn point(s: &str) -> Result<Pos, AocError> {
+    Ok(rules::point(s)?)
+}
+
+// "x,y" points, one per line.
+pub fn points(s: &str) -> Result<Vec<Pos>, AocError> {
+    Ok(rules::points(s)?)
+}
+
+// A pair of points joined by an arrow (e.g. "0,9 -> 5,9").
+pub fn point_pair(s: &str) -> Result<(Pos, Pos), AocError> {
+    Ok(rules::point_pair(s)?)
+}
+
+// A grid of single digits, one row per line (e.g. a height map).
+pub fn digit_grid(s: &str) -> Result<Grid2D<usize>, AocError> {
+    Grid2D::parse_with(s, |ch| {
+        ch.to_digit(10)
+            .map(|d| d as usize)
+            .ok_or_else(|| AocError::invalid_input("expected a digit"))
+    })
+}
+
+// Lines each parsed by a function (e.g. one instruction per line), reporting parse errors
+// relative to the whole input rather than to the line.
+pub fn lines<T, F>(s: &str, mut f: F) -> Result<Vec<T>, AocError>
+where
+    F: FnMut(&str) -> Result<T, AocError>,
+{
+    if s.is_empty() {
+        return Err(AocError::invalid_input("expected at least one line"));
+    }
+
+    s.lines()
+        .enumerate()
+        .map(|(i, text)| Section { line: i + 1, text }.parse(&mut f))
+        .collect()
+}
+
+// Section is a run of non-blank lines of an input along with the line number (from 1) of its
+// first line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Section<'a> {
+    // Parse the text of the section, reporting parse errors relative to the whole input rather
+    // than to the section.
+    pub fn parse<T, F>(&self, f: F) -> Result<T, AocError>
+    where
+        F: FnOnce(&'a str) -> Result<T, AocError>,
+    {
+        f(self.text).map_err(|e| match e {
+            AocError::Parse {
+                file,
+                line,
+                column,
+                expected,
+            } => AocError::Parse {
+                file,
+                line: line + self.line - 1,
+                column,
+                expected,
+            },
+            _ => e,
+        })
+    }
+}
+
+// Split an input into its blank line separated sections.
+pub fn sections(s: &str) -> Vec<Section<'_>> {
+    let mut rv = vec![];
+
+    // the first line and the byte range of the section being collected
+    let mut current: Option<(usize, usize, usize)> = None;
+    let mut offset = 0;
+
+    for (i, l) in s.split('\n').enumerate() {
+        if l.trim().is_empty() {
+            if let Some((line, start, end)) = current.take() {
+                rv.push(Section {
+                    line,
+                    text: &s[start..end],
+                });
+            }
+        } else {
+            let end = offset + l.trim_end_matches('\r').len();
+            current = match current {
+                Some((line, start, _)) => Some((line, start, end)),
+                None => Some((i + 1, offset, end)),
+            };
+        }
+
+        offset += l.len() + 1;
+    }
+
+    if let Some((line, start, end)) = current {
+        rv.push(Section {
+            line,
+            text: &s[start..end],
+        });
+    }
+
+    rv
+}
+
+peg::parser! {
+    grammar rules() for str {
+        pub rule unsigned() -> usize
+            = ns:$(['0'..='9']+)
+            {?
+                ns.parse().or(Err("unsigned integer"))
+            }
+
+        pub rule signed() -> isize
+            = ns:$(['-' | '+']? ['0'..='9']+)
+            {?
+                ns.parse().or(Err("signed integer"))
+            }
+
+        pub rule unsigned_list() -> Vec<usize>
+            = ns:unsigned() ++ comma()
+            {
+                ns
+            }
+
+        pub rule signed_list() -> Vec<isize>
+            = ns:signed() ++ comma()
+            {
+                ns
+            }
+
+        pub rule unsigned_table() -> Vec<Vec<usize>>
+            = rs:(_ ns:unsigned() ++ __ _ { ns }) ++ eol()
+            {
+                rs
+            }
+
+        pub rule signed_table() -> Vec<Vec<isize>>
+            = rs:(_ ns:signed() ++ __ _ { ns }) ++ eol()
+            {
+                rs
+            }
+
+        pub rule point() -> Pos
+            = x:signed() comma() y:signed()
+            {
+                Pos::new(x, y)
+            }
+
+        pub rule point_pair() -> (Pos, Pos)
+            = p1:point() _ "->" _ p2:point()
+            {
+                (p1, p2)
+            }
+
+        pub rule points() -> Vec<Pos>
+            = ps:point() ++ eol()
+            {
+                ps
+            }
+
+        rule comma()
+            = _ "," _
+
+        rule _()
+            = [' ' | '\t']*
+
+        rule __()
+            = [' ' | '\t']+
+
+        rule eol()
+            = "\r\n"
+            / "\n"
+            / "\r"
+    }
+}
+
+#[test]
+fn test_numbers() -> Result<(), AocError> {
+    assert_eq!(unsigned("42")?, 42);
+    assert_eq!(signed("-42")?, -42);
+    assert_eq!(signed("+42")?, 42);
+    assert_eq!(unsigned_list("3,4, 3,1,2")?, vec![3, 4, 3, 1, 2]);
+    assert_eq!(signed_list("-1,0,1")?, vec![-1, 0, 1]);
+    assert_eq!(
+        unsigned_table("22 13 17\n 8  2 23")?,
+        vec![vec![22, 13, 17], vec![8, 2, 23]]
+    );
+    assert_eq!(signed_table("-1 2\n3 -4")?, vec![vec![-1, 2], vec![3, -4]]);
+
+    assert!(unsigned("-42").is_err());
+    assert!(unsigned_list("3,,4").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_points_and_grids() -> Result<(), AocError> {
+    assert_eq!(point("6,10")?, Pos::new(6, 10));
+    assert_eq!(point("-6,10")?, Pos::new(-6, 10));
+    assert_eq!(
+        points("6,10\r\n0,14")?,
+        vec![Pos::new(6, 10), Pos::new(0, 14)]
+    );
+
+    let grid = digit_grid("219\n398")?;
+    assert_eq!(grid.get_yx(1, 2), Some(8));
+    assert!(digit_grid("219\n39").is_err());
+    assert!(digit_grid("219\n3x8").is_err());
+
+    assert_eq!(point_pair("0,9 -> 5,9")?, (Pos::new(0, 9), Pos::new(5, 9)));
+    assert!(point_pair("0,9 => 5,9").is_err());
+
+    let err = points("6,10\n0;14").err().unwrap();
+    assert!(matches!(
+        err,
+        AocError::Parse {
+            line: 2,
+            column: 2,
+            ..
+        }
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_lines() -> Result<(), AocError> {
+    let segments = lines("6,10 -> 0,14\r\n9,4 -> 3,4", |l| {
+        l.split(" -> ").map(point).collect::<Result<Vec<_>, _>>()
+    })?;
+    assert_eq!(
+        segments,
+        vec![
+            vec![Pos::new(6, 10), Pos::new(0, 14)],
+            vec![Pos::new(9, 4), Pos::new(3, 4)]
+        ]
+    );
+    assert!(lines("", unsigned).is_err());
+
+    let err = lines("1\n2\nx", unsigned).err().unwrap();
+    assert!(matches!(err, AocError::Parse { line: 3, .. }));
+
+    Ok(())
+}
+
+#[test]
+fn test_sections() -> Result<(), AocError> {
+    let input = "7,4,9\n\n22 13\n 8  2\n\n\n3 15\n9 18\n";
+    let sections = sections(input);
+
+    assert_eq!(
+        sections,
+        vec![
+            Section {
+                line: 1,
+                text: "7,4,9"
+            },
+            Section {
+                line: 3,
+                text: "22 13\n 8  2"
+            },
+            Section {
+                line: 7,
+                text: "3 15\n9 18"
+            },
+        ]
+    );
+
+    let err = sections[2].parse(points).err().unwrap();
+    assert!(matches!(err, AocError::Parse { line: 7, .. }));
+
+    Ok(())
+}
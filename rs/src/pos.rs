@@ -0,0 +1,350 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// Positions and the vectors between them.  Both are signed so that positions can be moved off a
+// grid (and checked) without casting; y grows downwards as it does in the rows of an input.
+
+// Pos is a position on a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Pos {
+    pub x: isize,
+    pub y: isize,
+}
+
+// Vec2 is the difference between two positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Pos {
+    pub const ORIGIN: Pos = Pos { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    // The position of a (y, x) grid index.
+    pub fn from_yx(y: usize, x: usize) -> Self {
+        Self {
+            x: x as isize,
+            y: y as isize,
+        }
+    }
+
+    // The (y, x) grid index of the position (None if either coordinate is negative).
+    pub fn to_yx(&self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+
+    // The positions from this one onwards in steps of a vector (without end, starting with this
+    // position).
+    pub fn ray(self, step: Vec2) -> impl Iterator<Item = Pos> {
+        std::iter::successors(Some(self), move |p| Some(*p + step))
+    }
+
+    // The positions on a horizontal, vertical or diagonal line from this position to another one
+    // (both included) or None if the line is not one of those.
+    pub fn line_to(self, other: Pos) -> Option<impl Iterator<Item = Pos>> {
+        let delta = other - self;
+        if delta.x != 0 && delta.y != 0 && delta.x.abs() != delta.y.abs() {
+            return None;
+        }
+
+        Some(self.ray(delta.signum()).take(delta.chebyshev_len() + 1))
+    }
+
+    // The distance when moving in 4 directions.
+    pub fn manhattan(&self, other: Pos) -> usize {
+        (other - *self).manhattan_len()
+    }
+
+    // The distance when moving in 8 directions.
+    pub fn chebyshev(&self, other: Pos) -> usize {
+        (other - *self).chebyshev_len()
+    }
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan_len(&self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    pub fn chebyshev_len(&self) -> usize {
+        self.x.unsigned_abs().max(self.y.unsigned_abs())
+    }
+
+    // The vector with each component reduced to -1, 0 or 1 (a single step in the same direction
+    // for horizontal, vertical and diagonal vectors).
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    // Rotate by 90 degrees clockwise (as seen on screen, where y grows downwards).
+    pub fn rotate_right(&self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    // Rotate by 90 degrees counterclockwise (as seen on screen, where y grows downwards).
+    pub fn rotate_left(&self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+impl Add<Vec2> for Pos {
+    type Output = Pos;
+
+    fn add(self, other: Vec2) -> Pos {
+        Pos::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign<Vec2> for Pos {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub<Vec2> for Pos {
+    type Output = Pos;
+
+    fn sub(self, other: Vec2) -> Pos {
+        Pos::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign<Vec2> for Pos {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+impl Sub for Pos {
+    type Output = Vec2;
+
+    fn sub(self, other: Pos) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, n: isize) -> Vec2 {
+        Vec2::new(self.x * n, self.y * n)
+    }
+}
+
+// Positions are ordered by row and then by column (the order of the points of an input).
+impl Ord for Pos {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Pos {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::fmt::Display for Pos {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(fmt, "{},{}", self.x, self.y)
+    }
+}
+
+// Dir4 is one of the 4 directions along the rows and columns of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    // In clockwise order.
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn vec(&self) -> Vec2 {
+        match self {
+            Self::Up => Vec2::new(0, -1),
+            Self::Right => Vec2::new(1, 0),
+            Self::Down => Vec2::new(0, 1),
+            Self::Left => Vec2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.turn_right().turn_right()
+    }
+}
+
+// Dir8 is one of the 4 directions of Dir4 or one of the 4 diagonals between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    // In clockwise order.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    pub fn vec(&self) -> Vec2 {
+        match self {
+            Self::Up => Vec2::new(0, -1),
+            Self::UpRight => Vec2::new(1, -1),
+            Self::Right => Vec2::new(1, 0),
+            Self::DownRight => Vec2::new(1, 1),
+            Self::Down => Vec2::new(0, 1),
+            Self::DownLeft => Vec2::new(-1, 1),
+            Self::Left => Vec2::new(-1, 0),
+            Self::UpLeft => Vec2::new(-1, -1),
+        }
+    }
+
+    // Turn clockwise by 45 degrees.
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    // Turn counterclockwise by 45 degrees.
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|d| d == self).unwrap()
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(d: Dir4) -> Self {
+        match d {
+            Dir4::Up => Self::Up,
+            Dir4::Right => Self::Right,
+            Dir4::Down => Self::Down,
+            Dir4::Left => Self::Left,
+        }
+    }
+}
+
+#[test]
+fn test_pos_arithmetic() {
+    let a = Pos::new(1, 2);
+    let b = Pos::new(4, -2);
+
+    assert_eq!(b - a, Vec2::new(3, -4));
+    assert_eq!(a + Vec2::new(3, -4), b);
+    assert_eq!(b - Vec2::new(3, -4), a);
+    assert_eq!(a.manhattan(b), 7);
+    assert_eq!(a.chebyshev(b), 4);
+    assert_eq!((b - a).signum(), Vec2::new(1, -1));
+    assert_eq!(-Vec2::new(3, -4) * 2, Vec2::new(-6, 8));
+
+    assert_eq!(Pos::from_yx(2, 1), a);
+    assert_eq!(a.to_yx(), Some((2, 1)));
+    assert_eq!(b.to_yx(), None);
+    assert!(Pos::new(5, 0) < Pos::new(0, 1));
+}
+
+#[test]
+fn test_lines() {
+    let a = Pos::new(1, 3);
+
+    assert_eq!(
+        a.ray(Vec2::new(1, -1)).take(3).collect::<Vec<_>>(),
+        vec![Pos::new(1, 3), Pos::new(2, 2), Pos::new(3, 1)]
+    );
+    assert_eq!(
+        a.line_to(Pos::new(1, 1)).map(|l| l.collect::<Vec<_>>()),
+        Some(vec![Pos::new(1, 3), Pos::new(1, 2), Pos::new(1, 1)])
+    );
+    assert_eq!(a.line_to(a).map(|l| l.count()), Some(1));
+    assert!(a.line_to(Pos::new(2, 1)).is_none());
+}
+
+#[test]
+fn test_directions() {
+    assert_eq!(Dir4::Up.vec().rotate_right(), Dir4::Right.vec());
+    assert_eq!(Dir4::Up.vec().rotate_left(), Dir4::Left.vec());
+    assert!(Dir4::ALL
+        .iter()
+        .all(|d| d.turn_right().vec() == d.vec().rotate_right()
+            && d.turn_left().vec() == d.vec().rotate_left()
+            && d.opposite().vec() == -d.vec()));
+
+    assert_eq!(Dir8::UpLeft.turn_right(), Dir8::Up);
+    assert_eq!(Dir8::Up.turn_left(), Dir8::UpLeft);
+    assert!(Dir8::ALL.iter().all(|d| d.opposite().vec() == -d.vec()));
+    assert_eq!(Dir8::from(Dir4::Down).vec(), Vec2::new(0, 1));
+}
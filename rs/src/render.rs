@@ -0,0 +1,331 @@
+use crate::grid2d::Grid2D;
+use crate::{AocError, Solution};
+use std::io::Write;
+use std::path::Path;
+
+// Renderers for grids so that they can be looked at in a terminal (through Display with a
+// character per cell) or outside of one as plain PBM (black and white), PGM (grey levels) or SVG
+// (colour) images.  The image formats are simple enough to be written without any dependencies.
+
+// Rgb is a colour of an SVG image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    // The colour a fraction (from 0.0 to 1.0) of the way from this colour to another one.
+    pub fn lerp(&self, other: Rgb, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+
+        Self(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+}
+
+impl std::fmt::Display for Rgb {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(fmt, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+// Palette is a list of colours indexed by a cell value (e.g. a height or an energy level).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colors: Vec<Rgb>,
+}
+
+impl Palette {
+    pub fn new(colors: Vec<Rgb>) -> Self {
+        Self { colors }
+    }
+
+    // A number of colours evenly spread from one colour to another (both included).
+    pub fn gradient(from: Rgb, to: Rgb, levels: usize) -> Self {
+        let steps = levels.saturating_sub(1).max(1) as f64;
+
+        Self::new(
+            (0..levels)
+                .map(|i| from.lerp(to, i as f64 / steps))
+                .collect(),
+        )
+    }
+
+    // The colour of a value (values past the end of the palette get its last colour).
+    pub fn get(&self, i: usize) -> Option<Rgb> {
+        self.colors
+            .get(i.min(self.colors.len().saturating_sub(1)))
+            .copied()
+    }
+}
+
+// Image is a grid of grey levels from 0 up to a maximum that a day renders its puzzle as, so it
+// can be written in any of the image formats.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub levels: Grid2D<u16>,
+    pub max: u16,
+}
+
+// ImageFormat is the format an image is written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    // Black where the level is the maximum and white elsewhere.
+    Pbm,
+    // Grey from black at level 0 to white at the maximum.
+    Pgm,
+    // Squares shaded from white to black by level (with nothing drawn at level 0).
+    Svg,
+}
+
+impl ImageFormat {
+    // The format given by the extension of a path.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "pbm" => Some(Self::Pbm),
+            "pgm" => Some(Self::Pgm),
+            "svg" => Some(Self::Svg),
+            _ => None,
+        }
+    }
+}
+
+impl Image {
+    // The pixel size of a cell of an SVG image.
+    const CELL_SIZE: usize = 10;
+
+    pub fn new(levels: Grid2D<u16>, max: u16) -> Self {
+        Self { levels, max }
+    }
+
+    pub fn write<W: Write>(&self, out: &mut W, format: ImageFormat) -> std::io::Result<()> {
+        match format {
+            ImageFormat::Pbm => self.levels.write_pbm(out, |v| v >= self.max),
+            ImageFormat::Pgm => self.levels.write_pgm(out, self.max, |v| v),
+            ImageFormat::Svg => {
+                let palette = Palette::gradient(Rgb::WHITE, Rgb::BLACK, self.max as usize + 1);
+
+                self.levels.write_svg(out, Self::CELL_SIZE, |v| {
+                    (v > 0).then(|| palette.get(v as usize)).flatten()
+                })
+            }
+        }
+    }
+
+    // Write the image to a file in the format given by its extension.
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        let name = path.display().to_string();
+        let format = ImageFormat::from_path(path).ok_or_else(|| {
+            AocError::invalid_input(
+                format!(
+                    "cannot tell the image format of {} (use .pbm, .pgm or .svg)",
+                    name
+                )
+                .as_str(),
+            )
+        })?;
+
+        let write = || -> std::io::Result<()> {
+            let mut out = std::io::BufWriter::new(std::fs::File::create(path)?);
+            self.write(&mut out, format)?;
+            out.flush()
+        };
+
+        write().map_err(|e| AocError::from(e).in_file(&name))
+    }
+}
+
+// Parse the input of a solution and render it (if the solution renders its input at all).
+pub fn run<S: Solution>(input: &str) -> Result<Option<Image>, AocError> {
+    let input = S::parse(&crate::normalize(input))?;
+
+    Ok(S::render(&input))
+}
+
+// Grid2DDisplay displays a grid as one line of characters per row.
+pub struct Grid2DDisplay<'a, T: Copy, F> {
+    grid: &'a Grid2D<T>,
+    f: F,
+}
+
+impl<'a, T, F> std::fmt::Display for Grid2DDisplay<'a, T, F>
+where
+    T: Copy,
+    F: Fn(T) -> char,
+{
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.grid.rows().enumerate().try_for_each(|(y, row)| {
+            if y > 0 {
+                writeln!(fmt)?;
+            }
+
+            row.map(&self.f).try_for_each(|ch| write!(fmt, "{}", ch))
+        })
+    }
+}
+
+// Grids of displayable values (e.g. digits or characters) display each value in turn.
+impl<T: Copy + std::fmt::Display> std::fmt::Display for Grid2D<T> {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.rows().enumerate().try_for_each(|(y, row)| {
+            if y > 0 {
+                writeln!(fmt)?;
+            }
+
+            row.map(|v| v.to_string())
+                .try_for_each(|s| write!(fmt, "{}", s))
+        })
+    }
+}
+
+impl<T: Copy> Grid2D<T> {
+    // Display the grid with a character for the value of each cell.
+    pub fn display<F: Fn(T) -> char>(&self, f: F) -> Grid2DDisplay<'_, T, F> {
+        Grid2DDisplay { grid: self, f }
+    }
+
+    // Write the grid as a plain PBM image with a pixel per cell, black where a predicate is true.
+    pub fn write_pbm<W, F>(&self, out: &mut W, mut black: F) -> std::io::Result<()>
+    where
+        W: Write,
+        F: FnMut(T) -> bool,
+    {
+        writeln!(out, "P1\n{} {}", self.width(), self.height())?;
+
+        self.rows().try_for_each(|row| {
+            let bits: Vec<_> = row.map(|v| if black(v) { "1" } else { "0" }).collect();
+
+            writeln!(out, "{}", bits.join(" "))
+        })
+    }
+
+    // Write the grid as a plain PGM image with a pixel per cell whose grey level (from black at 0
+    // to white at max) is the result of a function.  The format needs a max of at least 1, which
+    // a max of 0 is raised to.
+    pub fn write_pgm<W, F>(&self, out: &mut W, max: u16, mut level: F) -> std::io::Result<()>
+    where
+        W: Write,
+        F: FnMut(T) -> u16,
+    {
+        let max = max.max(1);
+        writeln!(out, "P2\n{} {}\n{}", self.width(), self.height(), max)?;
+
+        self.rows().try_for_each(|row| {
+            let levels: Vec<_> = row.map(|v| level(v).min(max).to_string()).collect();
+
+            writeln!(out, "{}", levels.join(" "))
+        })
+    }
+
+    // Write the grid as an SVG image of squares with sides of a number of pixels, coloured by a
+    // function (cells without a colour are left out).
+    pub fn write_svg<W, F>(
+        &self,
+        out: &mut W,
+        cell_size: usize,
+        mut color: F,
+    ) -> std::io::Result<()>
+    where
+        W: Write,
+        F: FnMut(T) -> Option<Rgb>,
+    {
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}">"#,
+            self.width() * cell_size,
+            self.height() * cell_size
+        )?;
+
+        self.iter().try_for_each(|p| match color(p.value) {
+            Some(c) => writeln!(
+                out,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                p.x * cell_size,
+                p.y * cell_size,
+                cell_size,
+                cell_size,
+                c
+            ),
+            None => Ok(()),
+        })?;
+
+        writeln!(out, "</svg>")
+    }
+}
+
+#[test]
+fn test_display() -> Result<(), crate::AocError> {
+    let grid = crate::parsers::digit_grid("219\n398")?;
+
+    assert_eq!(grid.to_string(), "219\n398");
+    assert_eq!(
+        grid.display(|v| if v > 5 { '#' } else { '.' }).to_string(),
+        "..#\n.##"
+    );
+    assert_eq!(Grid2D::filled(0, 0, 1).to_string(), "");
+
+    Ok(())
+}
+
+#[test]
+fn test_images() -> Result<(), crate::AocError> {
+    let grid = crate::parsers::digit_grid("219\n398")?;
+
+    let mut pbm = vec![];
+    grid.write_pbm(&mut pbm, |v| v > 5)?;
+    assert_eq!(String::from_utf8_lossy(&pbm), "P1\n3 2\n0 0 1\n0 1 1\n");
+
+    let mut pgm = vec![];
+    grid.write_pgm(&mut pgm, 8, |v| v as u16)?;
+    assert_eq!(String::from_utf8_lossy(&pgm), "P2\n3 2\n8\n2 1 8\n3 8 8\n");
+
+    let mut pgm = vec![];
+    grid.write_pgm(&mut pgm, 0, |v| v as u16)?;
+    assert_eq!(String::from_utf8_lossy(&pgm), "P2\n3 2\n1\n1 1 1\n1 1 1\n");
+
+    let palette = Palette::gradient(Rgb::BLACK, Rgb::WHITE, 10);
+    assert_eq!(palette.get(0), Some(Rgb::BLACK));
+    assert_eq!(palette.get(12), Some(Rgb::WHITE));
+    assert_eq!(
+        palette.get(5).map(|c| c.to_string()),
+        Some("#8e8e8e".to_string())
+    );
+
+    let mut svg = vec![];
+    grid.write_svg(&mut svg, 10, |v| if v == 9 { palette.get(v) } else { None })?;
+    let svg = String::from_utf8_lossy(&svg);
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="30" height="20">"#));
+    assert_eq!(svg.matches("<rect").count(), 2);
+    assert!(svg.contains(r##"<rect x="20" y="0" width="10" height="10" fill="#ffffff"/>"##));
+
+    Ok(())
+}
+
+#[test]
+fn test_image() -> Result<(), crate::AocError> {
+    let image = Image::new(crate::parsers::digit_grid("012\n210")?.map(|v| v as u16), 2);
+
+    let mut pbm = vec![];
+    image.write(&mut pbm, ImageFormat::Pbm)?;
+    assert_eq!(String::from_utf8_lossy(&pbm), "P1\n3 2\n0 0 1\n1 0 0\n");
+
+    let mut svg = vec![];
+    image.write(&mut svg, ImageFormat::Svg)?;
+    let svg = String::from_utf8_lossy(&svg);
+    assert_eq!(svg.matches("<rect").count(), 4);
+    assert!(svg.contains(r##"<rect x="20" y="0" width="10" height="10" fill="#000000"/>"##));
+
+    assert_eq!(
+        ImageFormat::from_path(Path::new("out/day09.pgm")),
+        Some(ImageFormat::Pgm)
+    );
+    assert_eq!(ImageFormat::from_path(Path::new("day09.png")), None);
+    assert!(image.save(Path::new("day09")).is_err());
+
+    Ok(())
+}
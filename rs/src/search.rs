@@ -0,0 +1,458 @@
+use crate::grid2d::{Grid2D, Grid2DPoint, Neighborhood};
+use crate::pos::Pos;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+// Searches over the points of a Grid2D.  Every search starts from a single position and moves
+// between the points of a Neighborhood.
+
+// Search is the result of a search: the distance from the start to every point that was reached
+// and the previous point on a shortest path to each of them.
+#[derive(Debug, Clone)]
+pub struct Search {
+    start: Pos,
+    distances: Grid2D<Option<usize>>,
+    previous: Grid2D<Option<Pos>>,
+}
+
+impl Search {
+    fn init<T: Copy>(grid: &Grid2D<T>, start: Pos) -> Self {
+        let mut rv = Self {
+            start,
+            distances: grid.map(|_| None),
+            previous: grid.map(|_| None),
+        };
+        rv.distances.set(start, Some(0));
+
+        rv
+    }
+
+    pub fn start(&self) -> Pos {
+        self.start
+    }
+
+    // The distance from the start to a position (None if the position was not reached).
+    pub fn distance(&self, pos: Pos) -> Option<usize> {
+        self.distances.get(pos).flatten()
+    }
+
+    // The distance map (None for the points that were not reached).
+    pub fn distances(&self) -> &Grid2D<Option<usize>> {
+        &self.distances
+    }
+
+    // The positions that were reached (including the start).
+    pub fn reached(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.distances
+            .iter()
+            .filter(|p| p.value.is_some())
+            .map(|p| p.pos())
+    }
+
+    // A shortest path from the start to a position (both included) or None if it was not reached.
+    pub fn path_to(&self, pos: Pos) -> Option<Vec<Pos>> {
+        self.distance(pos)?;
+
+        let mut rv = vec![pos];
+        let mut curr = pos;
+        while let Some(p) = self.previous.get(curr)? {
+            rv.push(p);
+            curr = p;
+        }
+        rv.reverse();
+
+        Some(rv)
+    }
+
+    fn visit(&mut self, from: Pos, to: Pos, distance: usize) -> bool {
+        match self.distance(to) {
+            Some(d) if d <= distance => false,
+            _ => {
+                self.distances.set(to, Some(distance));
+                self.previous.set(to, Some(from));
+
+                true
+            }
+        }
+    }
+}
+
+// Breadth first search from a point where every step costs 1.  The passable predicate decides
+// whether a point can be entered (the start is always included).
+pub fn bfs<T, F>(
+    grid: &Grid2D<T>,
+    start: Pos,
+    neighborhood: Neighborhood,
+    mut passable: F,
+) -> Search
+where
+    T: Copy,
+    F: FnMut(&Grid2DPoint<T>) -> bool,
+{
+    let mut rv = Search::init(grid, start);
+    if !grid.contains(start) {
+        return rv;
+    }
+
+    let mut queue = VecDeque::from([start]);
+    while let Some(curr) = queue.pop_front() {
+        let distance = rv.distance(curr).unwrap_or_default() + 1;
+
+        for p in grid.neighbors_of(curr, neighborhood) {
+            if rv.distance(p.pos()).is_none() && passable(&p) {
+                rv.visit(curr, p.pos(), distance);
+                queue.push_back(p.pos());
+            }
+        }
+    }
+
+    rv
+}
+
+// The points connected to a point through passable points (including the point itself).
+pub fn flood_fill<T, F>(
+    grid: &Grid2D<T>,
+    start: Pos,
+    neighborhood: Neighborhood,
+    passable: F,
+) -> Vec<Grid2DPoint<T>>
+where
+    T: Copy,
+    F: FnMut(&Grid2DPoint<T>) -> bool,
+{
+    bfs(grid, start, neighborhood, passable)
+        .reached()
+        .filter_map(|pos| grid.point(pos))
+        .collect()
+}
+
+// Bounds is the (inclusive) bounding box of a set of points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min_y: usize,
+    pub min_x: usize,
+    pub max_y: usize,
+    pub max_x: usize,
+}
+
+impl Bounds {
+    fn of(y: usize, x: usize) -> Self {
+        Self {
+            min_y: y,
+            min_x: x,
+            max_y: y,
+            max_x: x,
+        }
+    }
+
+    fn extend(&mut self, y: usize, x: usize) {
+        self.min_y = self.min_y.min(y);
+        self.min_x = self.min_x.min(x);
+        self.max_y = self.max_y.max(y);
+        self.max_x = self.max_x.max(x);
+    }
+}
+
+// Component is one connected set of points of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Component {
+    pub label: usize,
+    pub size: usize,
+    pub bounds: Bounds,
+}
+
+// Components are the connected components of a grid: a label for every point that belongs to a
+// component and the size and bounds of each component (indexed by label).
+#[derive(Debug, Clone)]
+pub struct Components {
+    labels: Grid2D<Option<usize>>,
+    components: Vec<Component>,
+}
+
+impl Components {
+    // The label of the component a position belongs to (None if it belongs to none).
+    pub fn label(&self, pos: Pos) -> Option<usize> {
+        self.labels.get(pos).flatten()
+    }
+
+    pub fn labels(&self) -> &Grid2D<Option<usize>> {
+        &self.labels
+    }
+
+    pub fn get(&self, label: usize) -> Option<&Component> {
+        self.components.get(label)
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Component> {
+        self.components.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+}
+
+// Label the connected components of the points that match a predicate, where points are connected
+// if they are adjacent under the connectivity neighborhood.  Labels are numbered from 0 in the
+// order the components are first met scanning the grid row by row.
+pub fn label_components<T, F>(
+    grid: &Grid2D<T>,
+    mut predicate: F,
+    connectivity: Neighborhood,
+) -> Components
+where
+    T: Copy,
+    F: FnMut(&Grid2DPoint<T>) -> bool,
+{
+    // the mask keeps the size and boundary of the grid (nothing beyond the edges matches)
+    let mut mask = grid.map(|_| false);
+    grid.iter().filter(|p| predicate(p)).for_each(|p| {
+        mask.set_yx(p.y, p.x, true);
+    });
+
+    let mut rv = Components {
+        labels: grid.map(|_| None),
+        components: vec![],
+    };
+
+    for p in mask.iter().filter(|p| p.value) {
+        if rv.label(p.pos()).is_some() {
+            continue;
+        }
+
+        let mut component = Component {
+            label: rv.components.len(),
+            size: 0,
+            bounds: Bounds::of(p.y, p.x),
+        };
+
+        rv.labels.set_yx(p.y, p.x, Some(component.label));
+        let mut queue = VecDeque::from([(p.y, p.x)]);
+        while let Some(curr) = queue.pop_front() {
+            component.size += 1;
+            component.bounds.extend(curr.0, curr.1);
+
+            for n in mask.neighbors(curr.0, curr.1, connectivity) {
+                if n.value && rv.label(n.pos()).is_none() {
+                    rv.labels.set_yx(n.y, n.x, Some(component.label));
+                    queue.push_back((n.y, n.x));
+                }
+            }
+        }
+
+        rv.components.push(component);
+    }
+
+    rv
+}
+
+// Shortest paths from a point to every other point.  The cost function gives the cost of moving
+// from one point to an adjacent point or None if the move is not possible.
+pub fn dijkstra<T, F>(grid: &Grid2D<T>, start: Pos, neighborhood: Neighborhood, cost: F) -> Search
+where
+    T: Copy,
+    F: FnMut(&Grid2DPoint<T>, &Grid2DPoint<T>) -> Option<usize>,
+{
+    search(grid, start, None, neighborhood, cost, |_| 0)
+}
+
+// The shortest path from a point to a goal.  The heuristic estimates the remaining cost from a
+// point to the goal and must never overestimate it for the path to be a shortest one.  The search
+// stops once the goal is reached so the distance map is only partially filled in.
+pub fn astar<T, F, H>(
+    grid: &Grid2D<T>,
+    start: Pos,
+    goal: Pos,
+    neighborhood: Neighborhood,
+    cost: F,
+    heuristic: H,
+) -> Search
+where
+    T: Copy,
+    F: FnMut(&Grid2DPoint<T>, &Grid2DPoint<T>) -> Option<usize>,
+    H: FnMut(Pos) -> usize,
+{
+    search(grid, start, Some(goal), neighborhood, cost, heuristic)
+}
+
+fn search<T, F, H>(
+    grid: &Grid2D<T>,
+    start: Pos,
+    goal: Option<Pos>,
+    neighborhood: Neighborhood,
+    mut cost: F,
+    mut heuristic: H,
+) -> Search
+where
+    T: Copy,
+    F: FnMut(&Grid2DPoint<T>, &Grid2DPoint<T>) -> Option<usize>,
+    H: FnMut(Pos) -> usize,
+{
+    let mut rv = Search::init(grid, start);
+    if !grid.contains(start) {
+        return rv;
+    }
+
+    // the queue is ordered by estimated total cost and holds the distance the point was queued
+    // with so that stale entries can be skipped
+    let mut queue = BinaryHeap::from([Reverse((heuristic(start), 0, start))]);
+    while let Some(Reverse((_, distance, curr))) = queue.pop() {
+        if rv.distance(curr) != Some(distance) {
+            continue;
+        }
+        if Some(curr) == goal {
+            break;
+        }
+
+        let curr_point = match grid.point(curr) {
+            Some(p) => p,
+            None => continue,
+        };
+
+        grid.neighbors_of(curr, neighborhood).for_each(|p| {
+            if let Some(c) = cost(&curr_point, &p) {
+                let next = p.pos();
+                if rv.visit(curr, next, distance + c) {
+                    queue.push(Reverse((
+                        distance + c + heuristic(next),
+                        distance + c,
+                        next,
+                    )));
+                }
+            }
+        });
+    }
+
+    rv
+}
+
+#[test]
+fn test_bfs() -> Result<(), crate::AocError> {
+    let grid = Grid2D::parse_with("..#.\n.##.\n....", |ch| Ok(ch == '#'))?;
+    let search = bfs(&grid, Pos::new(0, 0), Neighborhood::Four, |p| !p.value);
+
+    assert_eq!(search.distance(Pos::new(0, 0)), Some(0));
+    assert_eq!(search.distance(Pos::new(3, 0)), Some(7));
+    assert_eq!(search.distance(Pos::new(2, 0)), None);
+    assert_eq!(search.reached().count(), 9);
+    assert_eq!(
+        search.path_to(Pos::new(3, 0)),
+        Some(vec![
+            Pos::new(0, 0),
+            Pos::new(0, 1),
+            Pos::new(0, 2),
+            Pos::new(1, 2),
+            Pos::new(2, 2),
+            Pos::new(3, 2),
+            Pos::new(3, 1),
+            Pos::new(3, 0)
+        ])
+    );
+    assert_eq!(search.path_to(Pos::new(2, 0)), None);
+
+    let basin = flood_fill(&grid, Pos::new(3, 0), Neighborhood::Four, |p| !p.value);
+    assert_eq!(basin.len(), 9);
+
+    Ok(())
+}
+
+#[test]
+fn test_label_components() -> Result<(), crate::AocError> {
+    let grid = Grid2D::parse_with("##..#\n#...#\n..#..\n.#..#", |ch| Ok(ch == '#'))?;
+
+    let components = label_components(&grid, |p| p.value, Neighborhood::Four);
+    assert_eq!(components.len(), 5);
+    assert_eq!(components.label(Pos::new(0, 0)), Some(0));
+    assert_eq!(components.label(Pos::new(0, 1)), Some(0));
+    assert_eq!(components.label(Pos::new(4, 1)), Some(1));
+    assert_eq!(components.label(Pos::new(2, 0)), None);
+    assert_eq!(
+        components.iter().map(|c| c.size).collect::<Vec<_>>(),
+        vec![3, 2, 1, 1, 1]
+    );
+    assert_eq!(
+        components.get(0).map(|c| c.bounds),
+        Some(Bounds {
+            min_y: 0,
+            min_x: 0,
+            max_y: 1,
+            max_x: 1
+        })
+    );
+
+    // diagonally adjacent points are connected with 8-way connectivity
+    let components = label_components(&grid, |p| p.value, Neighborhood::Eight);
+    assert_eq!(components.len(), 4);
+    assert_eq!(
+        components.label(Pos::new(2, 2)),
+        components.label(Pos::new(1, 3))
+    );
+
+    // points in the corners are adjacent across the edges of a wrapped grid
+    let grid = Grid2D::parse_with("#..#\n....\n#..#", |ch| Ok(ch == '#'))?;
+    assert_eq!(
+        label_components(&grid, |p| p.value, Neighborhood::Four).len(),
+        4
+    );
+    let grid = grid.with_boundary(crate::grid_nd::Boundary::Wrap);
+    let components = label_components(&grid, |p| p.value, Neighborhood::Four);
+    assert_eq!(components.len(), 1);
+    assert_eq!(components.get(0).map(|c| c.size), Some(4));
+
+    // an empty grid has no components
+    let components = label_components(&Grid2D::filled(0, 0, true), |p| p.value, Neighborhood::Four);
+    assert!(components.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_dijkstra_and_astar() -> Result<(), crate::AocError> {
+    let grid = crate::parsers::digit_grid("1163\n1381\n2136\n3694")?;
+    let cost = |_: &Grid2DPoint<usize>, to: &Grid2DPoint<usize>| Some(to.value);
+
+    let search = dijkstra(&grid, Pos::new(0, 0), Neighborhood::Four, cost);
+    assert_eq!(search.distance(Pos::new(3, 3)), Some(17));
+    assert_eq!(search.distance(Pos::new(3, 0)), Some(10));
+
+    let path = search.path_to(Pos::new(3, 3)).unwrap();
+    assert_eq!(path.first(), Some(&Pos::new(0, 0)));
+    assert_eq!(path.last(), Some(&Pos::new(3, 3)));
+    assert_eq!(
+        path.iter()
+            .skip(1)
+            .filter_map(|p| grid.get(*p))
+            .sum::<usize>(),
+        17
+    );
+
+    let search = astar(
+        &grid,
+        Pos::new(0, 0),
+        Pos::new(3, 3),
+        Neighborhood::Four,
+        cost,
+        |p| p.manhattan(Pos::new(3, 3)),
+    );
+    assert_eq!(search.distance(Pos::new(3, 3)), Some(17));
+    assert_eq!(
+        search.path_to(Pos::new(3, 3)).map(|p| p.len()),
+        Some(path.len())
+    );
+
+    // impassable moves are never taken
+    let search = dijkstra(&grid, Pos::new(0, 0), Neighborhood::Four, |_, to| {
+        if to.value == 9 {
+            None
+        } else {
+            Some(to.value)
+        }
+    });
+    assert_eq!(search.distance(Pos::new(2, 3)), None);
+
+    Ok(())
+}
@@ -0,0 +1,327 @@
+use crate::grid2d::{Grid2D, Neighborhood};
+use crate::pos::Pos;
+use std::collections::HashMap;
+
+// SparseGrid is an unbounded grid that only stores the points that have been set.  Points are
+// signed (y, x) pairs so a sparse grid can grow in every direction and the bounding box of the
+// points that have been set is tracked as they are added.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T: Copy> {
+    cells: HashMap<(isize, isize), T>,
+    bounds: Option<SparseBounds>,
+}
+
+// SparseBounds is the (inclusive) bounding box of the points of a sparse grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SparseBounds {
+    pub min_y: isize,
+    pub min_x: isize,
+    pub max_y: isize,
+    pub max_x: isize,
+}
+
+impl SparseBounds {
+    fn of(y: isize, x: isize) -> Self {
+        Self {
+            min_y: y,
+            min_x: x,
+            max_y: y,
+            max_x: x,
+        }
+    }
+
+    fn extend(self, y: isize, x: isize) -> Self {
+        Self {
+            min_y: self.min_y.min(y),
+            min_x: self.min_x.min(x),
+            max_y: self.max_y.max(y),
+            max_x: self.max_x.max(x),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.min_x.abs_diff(self.max_x) + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.min_y.abs_diff(self.max_y) + 1
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        (self.min_y..=self.max_y).contains(&pos.y) && (self.min_x..=self.max_x).contains(&pos.x)
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct SparsePoint<T> {
+    pub x: isize,
+    pub y: isize,
+    pub value: T,
+}
+
+impl<T> SparsePoint<T> {
+    pub fn pos(&self) -> Pos {
+        Pos::new(self.x, self.y)
+    }
+}
+
+impl<T: Copy> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T: Copy> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // The points of a dense grid that match a predicate.
+    pub fn from_grid<F>(grid: &Grid2D<T>, mut keep: F) -> Self
+    where
+        F: FnMut(T) -> bool,
+    {
+        grid.iter()
+            .filter(|p| keep(p.value))
+            .map(|p| SparsePoint {
+                x: p.x as isize,
+                y: p.y as isize,
+                value: p.value,
+            })
+            .collect()
+    }
+
+    // A dense grid covering the bounds of the sparse grid (with the top left of the bounds at
+    // (0, 0)) where the points that have not been set have the empty value.
+    pub fn to_grid(&self, empty: T) -> Grid2D<T> {
+        let bounds = match self.bounds {
+            Some(b) => b,
+            None => return Grid2D::filled(0, 0, empty),
+        };
+
+        let mut rv = Grid2D::filled(bounds.width(), bounds.height(), empty);
+        self.iter().for_each(|p| {
+            rv.set_yx(
+                p.y.abs_diff(bounds.min_y),
+                p.x.abs_diff(bounds.min_x),
+                p.value,
+            );
+        });
+
+        rv
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // The bounding box of the points that have been set (None if no point has been set).
+    pub fn bounds(&self) -> Option<SparseBounds> {
+        self.bounds
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<T> {
+        self.get_yx(pos.y, pos.x)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.get_mut_yx(pos.y, pos.x)
+    }
+
+    pub fn get_mut_or_insert(&mut self, pos: Pos, default: T) -> &mut T {
+        self.get_mut_or_insert_yx(pos.y, pos.x, default)
+    }
+
+    pub fn set(&mut self, pos: Pos, v: T) -> Option<T> {
+        self.set_yx(pos.y, pos.x, v)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.remove_yx(pos.y, pos.x)
+    }
+
+    pub fn get_yx(&self, y: isize, x: isize) -> Option<T> {
+        self.cells.get(&(y, x)).copied()
+    }
+
+    pub fn get_mut_yx(&mut self, y: isize, x: isize) -> Option<&mut T> {
+        self.cells.get_mut(&(y, x))
+    }
+
+    // The value of a point, setting it to a default value first if it has not been set.
+    pub fn get_mut_or_insert_yx(&mut self, y: isize, x: isize, default: T) -> &mut T {
+        self.extend_bounds(y, x);
+
+        self.cells.entry((y, x)).or_insert(default)
+    }
+
+    // Set the value of a point, returning the previous value (if it had been set).
+    pub fn set_yx(&mut self, y: isize, x: isize, v: T) -> Option<T> {
+        self.extend_bounds(y, x);
+
+        self.cells.insert((y, x), v)
+    }
+
+    pub fn remove_yx(&mut self, y: isize, x: isize) -> Option<T> {
+        let rv = self.cells.remove(&(y, x));
+
+        // the bounds only shrink when a point on their edge is removed
+        if let Some(b) = self.bounds {
+            if rv.is_some() && (y == b.min_y || y == b.max_y || x == b.min_x || x == b.max_x) {
+                self.bounds = self
+                    .cells
+                    .keys()
+                    .fold(None, |acc: Option<SparseBounds>, (y, x)| match acc {
+                        Some(b) => Some(b.extend(*y, *x)),
+                        None => Some(SparseBounds::of(*y, *x)),
+                    });
+            }
+        }
+
+        rv
+    }
+
+    fn extend_bounds(&mut self, y: isize, x: isize) {
+        self.bounds = match self.bounds {
+            Some(b) => Some(b.extend(y, x)),
+            None => Some(SparseBounds::of(y, x)),
+        };
+    }
+
+    // The points that have been set (in no particular order).
+    pub fn iter(&self) -> impl Iterator<Item = SparsePoint<T>> + '_ {
+        self.cells.iter().map(|((y, x), value)| SparsePoint {
+            x: *x,
+            y: *y,
+            value: *value,
+        })
+    }
+
+    // The points around a point that have been set.
+    pub fn neighbors(
+        &self,
+        y: isize,
+        x: isize,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = SparsePoint<T>> + '_ {
+        neighborhood.offsets().iter().filter_map(move |(dy, dx)| {
+            let (y, x) = (y + dy, x + dx);
+
+            self.get_yx(y, x).map(|value| SparsePoint { x, y, value })
+        })
+    }
+
+    pub fn neighbors_of(
+        &self,
+        pos: Pos,
+        neighborhood: Neighborhood,
+    ) -> impl Iterator<Item = SparsePoint<T>> + '_ {
+        self.neighbors(pos.y, pos.x, neighborhood)
+    }
+
+    // Render the points within some bounds as one line of characters per row.
+    pub fn render_bounds<F>(&self, bounds: SparseBounds, mut f: F) -> String
+    where
+        F: FnMut(Option<T>) -> char,
+    {
+        (bounds.min_y..=bounds.max_y)
+            .map(|y| {
+                (bounds.min_x..=bounds.max_x)
+                    .map(|x| f(self.get_yx(y, x)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // Render the points within the bounds of the sparse grid.
+    pub fn render<F>(&self, f: F) -> String
+    where
+        F: FnMut(Option<T>) -> char,
+    {
+        match self.bounds {
+            Some(bounds) => self.render_bounds(bounds, f),
+            None => String::new(),
+        }
+    }
+}
+
+impl<T: Copy> std::iter::FromIterator<SparsePoint<T>> for SparseGrid<T> {
+    fn from_iter<I: std::iter::IntoIterator<Item = SparsePoint<T>>>(iter: I) -> Self {
+        let mut rv = Self::new();
+        iter.into_iter().for_each(|p| {
+            rv.set_yx(p.y, p.x, p.value);
+        });
+
+        rv
+    }
+}
+
+impl<T: Copy> std::iter::FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: std::iter::IntoIterator<Item = (Pos, T)>>(iter: I) -> Self {
+        let mut rv = Self::new();
+        iter.into_iter().for_each(|(pos, v)| {
+            rv.set(pos, v);
+        });
+
+        rv
+    }
+}
+
+#[test]
+fn test_set_and_bounds() {
+    let mut grid = SparseGrid::new();
+    assert_eq!(grid.bounds(), None);
+    assert_eq!(grid.render(|_| '#'), "");
+
+    assert_eq!(grid.set_yx(-1, 2, 'a'), None);
+    assert_eq!(grid.set_yx(1, -3, 'b'), None);
+    assert_eq!(grid.set_yx(1, -3, 'c'), Some('b'));
+    *grid.get_mut_or_insert_yx(0, 0, 'd') = 'e';
+
+    assert_eq!(grid.len(), 3);
+    assert_eq!(grid.get_yx(0, 0), Some('e'));
+    assert_eq!(
+        grid.bounds(),
+        Some(SparseBounds {
+            min_y: -1,
+            min_x: -3,
+            max_y: 1,
+            max_x: 2
+        })
+    );
+    assert_eq!(grid.render(|v| v.unwrap_or('.')), ".....a\n...e..\nc.....");
+
+    assert_eq!(grid.remove_yx(1, -3), Some('c'));
+    assert_eq!(grid.render(|v| v.unwrap_or('.')), "..a\ne..");
+    assert_eq!(grid.neighbors(0, 1, Neighborhood::Eight).count(), 2);
+
+    assert_eq!(grid.set(Pos::new(3, 0), 'f'), None);
+    assert_eq!(grid.get(Pos::new(3, 0)), Some('f'));
+    assert!(grid.bounds().unwrap().contains(Pos::new(3, -1)));
+    assert_eq!(grid.remove(Pos::new(3, 0)), Some('f'));
+}
+
+#[test]
+fn test_grid_conversion() -> Result<(), crate::AocError> {
+    let dense = crate::parsers::digit_grid("102\n000\n030")?;
+    let sparse = SparseGrid::from_grid(&dense, |v| v > 0);
+
+    assert_eq!(sparse.len(), 3);
+    assert_eq!(sparse.get_yx(2, 1), Some(3));
+    assert_eq!(sparse.get_yx(1, 1), None);
+
+    let dense = sparse.to_grid(0);
+    assert_eq!((dense.width(), dense.height()), (3, 3));
+    assert_eq!(dense.get_yx(0, 2), Some(2));
+    assert_eq!(dense.get_yx(1, 1), Some(0));
+
+    Ok(())
+}